@@ -0,0 +1,67 @@
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, Api, BankMsg, CosmosMsg, QuerierWrapper, StdResult, Uint128, WasmMsg
+};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
+
+// Prefix used for CW20 tokens in the claimable ledger, e.g. cw20:inj1...
+const CW20_PREFIX: &str = "cw20:";
+
+// Asset a purchase is paid in
+#[derive(Clone, Debug, PartialEq)]
+pub enum PaymentAsset {
+    Native(String),
+    Cw20(Addr),
+}
+
+impl PaymentAsset {
+    // Parse a claimable ledger key back into an asset
+    pub fn from_ledger_key(api: &dyn Api, key: &str) -> StdResult<Self> {
+        match key.strip_prefix(CW20_PREFIX) {
+            Some(contract) => Ok(PaymentAsset::Cw20(api.addr_validate(contract)?)),
+            None => Ok(PaymentAsset::Native(key.to_string())),
+        }
+    }
+    
+    // Key under which balances of this asset are kept in the claimable ledger
+    pub fn ledger_key(&self) -> String {
+        match self {
+            PaymentAsset::Native(denom) => denom.clone(),
+            PaymentAsset::Cw20(contract) => format!("{}{}", CW20_PREFIX, contract),
+        }
+    }
+    
+    // Balance of this asset held by `address`
+    pub fn query_balance(&self, querier: &QuerierWrapper, address: &Addr) -> StdResult<Uint128> {
+        match self {
+            PaymentAsset::Native(denom) => Ok(querier.query_balance(address, denom)?.amount),
+            PaymentAsset::Cw20(contract) => {
+                let response: BalanceResponse = querier.query_wasm_smart(
+                    contract,
+                    &Cw20QueryMsg::Balance { address: address.to_string() },
+                )?;
+                Ok(response.balance)
+            },
+        }
+    }
+    
+    pub fn transfer_msg(&self, recipient: &Addr, amount: Uint128) -> StdResult<CosmosMsg> {
+        let msg = match self {
+            PaymentAsset::Native(denom) => BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: coins(amount.u128(), denom),
+            }
+            .into(),
+            PaymentAsset::Cw20(contract) => WasmMsg::Execute {
+                contract_addr: contract.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: recipient.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            }
+            .into(),
+        };
+        
+        Ok(msg)
+    }
+}
@@ -1,12 +1,13 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
-use cw20::Cw20ReceiveMsg;
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response};
 
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::msg::{ExecuteMsg, InstantiateMsg, LaunchParams, MigrateMsg, QueryMsg};
 use crate::state::{Config, CONFIG};
+use crate::utils::DEFAULT_UNBONDING_PERIOD;
 use crate::execute;
+use crate::migrate;
 use crate::query;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -19,10 +20,30 @@ pub fn instantiate(
     let platform_wallet = deps.api.addr_validate(&msg.platform_wallet)?;
     let oracle_authority = deps.api.addr_validate(&msg.oracle_authority)?;
     
+    let referral_share_bps = msg.referral_share_bps.unwrap_or(0);
+    if referral_share_bps > 10_000 {
+        return Err(ContractError::InvalidReferralShare {});
+    }
+    
+    let holder_reward_bps = msg.holder_reward_bps.unwrap_or(0);
+    let staker_reward_bps = msg.staker_reward_bps.unwrap_or(0);
+    // Summed as u32 so that oversized shares are rejected rather than overflowing
+    let total_share_bps = u32::from(referral_share_bps) + u32::from(holder_reward_bps) + u32::from(staker_reward_bps);
+    if total_share_bps > 10_000 {
+        return Err(ContractError::InvalidFeeShares {});
+    }
+    
     let config = Config {
         platform_wallet,
         oracle_authority,
         authority: info.sender.clone(),
+        referral_share_bps,
+        holder_reward_bps,
+        staker_reward_bps,
+        unbonding_period: msg.unbonding_period.unwrap_or(DEFAULT_UNBONDING_PERIOD),
+        master_nft_contract: None,
+        launch_fee: msg.launch_fee.unwrap_or_default(),
+        moderator: msg.moderator.map(|moderator| deps.api.addr_validate(&moderator)).transpose()?,
     };
     
     CONFIG.save(deps.storage, &config)?;
@@ -31,7 +52,11 @@ pub fn instantiate(
         .add_attribute("method", "instantiate")
         .add_attribute("authority", info.sender)
         .add_attribute("platform_wallet", msg.platform_wallet)
-        .add_attribute("oracle_authority", msg.oracle_authority))
+        .add_attribute("oracle_authority", msg.oracle_authority)
+        .add_attribute("referral_share_bps", referral_share_bps.to_string())
+        .add_attribute("holder_reward_bps", holder_reward_bps.to_string())
+        .add_attribute("staker_reward_bps", staker_reward_bps.to_string())
+        .add_attribute("launch_fee", config.launch_fee.to_string()))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -42,27 +67,198 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::UpdatePlatform { new_platform_wallet, new_oracle_authority } => {
-            execute::update_platform(deps, env, info, new_platform_wallet, new_oracle_authority)
-        },
-        ExecuteMsg::InitializeTokenMetadata { id, name, music_uri, mint } => {
-            execute::initialize_token_metadata(deps, env, info, id, name, music_uri, mint)
-        },
+        ExecuteMsg::UpdatePlatform {
+            new_platform_wallet,
+            new_oracle_authority,
+            new_referral_share_bps,
+            new_holder_reward_bps,
+            new_staker_reward_bps,
+            new_unbonding_period,
+            new_launch_fee,
+        } => execute::update_platform(
+            deps,
+            env,
+            info,
+            new_platform_wallet,
+            new_oracle_authority,
+            new_referral_share_bps,
+            new_holder_reward_bps,
+            new_staker_reward_bps,
+            new_unbonding_period,
+            new_launch_fee,
+        ),
+        ExecuteMsg::InitializeTokenMetadata {
+            id,
+            name,
+            music_uri,
+            content_hash,
+            mint,
+            creator,
+            royalty_bps,
+            vesting,
+            mint_master_nft,
+            details,
+        } => execute::initialize_token_metadata(
+            deps,
+            env,
+            info,
+            id,
+            name,
+            music_uri,
+            content_hash,
+            mint,
+            creator,
+            royalty_bps,
+            vesting,
+            mint_master_nft,
+            details,
+        ),
         ExecuteMsg::InitializeTokenOracle { mint } => {
             execute::initialize_token_oracle(deps, env, info, mint)
         },
+        ExecuteMsg::LaunchToken {
+            subdenom,
+            id,
+            name,
+            music_uri,
+            content_hash,
+            creator,
+            royalty_bps,
+            mint_master_nft,
+            details,
+            launch,
+            initial_buy,
+        } => execute::launch_token(
+            deps,
+            env,
+            info,
+            subdenom,
+            id,
+            name,
+            music_uri,
+            content_hash,
+            creator,
+            royalty_bps,
+            mint_master_nft,
+            details,
+            launch,
+            initial_buy,
+        ),
+        ExecuteMsg::UpdateTokenMetadata { mint, name, music_uri, content_hash, details } => {
+            execute::update_token_metadata(
+                deps,
+                env,
+                info,
+                mint,
+                name,
+                music_uri,
+                content_hash,
+                details,
+            )
+        },
+        ExecuteMsg::FreezeMetadata { mint } => {
+            execute::freeze_metadata(deps, env, info, mint)
+        },
         ExecuteMsg::SetupVaultAccount { mint } => {
             execute::setup_vault_account(deps, env, info, mint)
         },
         ExecuteMsg::UpdateOracle { mint, new_view_count } => {
             execute::update_oracle(deps, env, info, mint, new_view_count)
         },
-        ExecuteMsg::PurchaseToken { mint, amount_tokens } => {
-            execute::purchase_token(deps, env, info, mint, amount_tokens)
+        ExecuteMsg::PurchaseToken { mint, amount_tokens, referrer, proof } => {
+            execute::purchase_token(deps, env, info, mint, amount_tokens, referrer, proof)
+        },
+        ExecuteMsg::SetRevenueSplit { mint, recipients } => {
+            execute::set_revenue_split(deps, env, info, mint, recipients)
+        },
+        ExecuteMsg::SetLaunchConfig {
+            mint,
+            start_time,
+            allowlist_duration,
+            protection_duration,
+            max_per_address,
+            max_per_tx,
+            presale,
+        } => execute::set_launch_config(
+            deps,
+            env,
+            info,
+            mint,
+            LaunchParams {
+                start_time,
+                allowlist_duration,
+                protection_duration,
+                max_per_address,
+                max_per_tx,
+                presale,
+            },
+        ),
+        ExecuteMsg::UpdateLaunchAllowlist { mint, add, remove } => {
+            execute::update_launch_allowlist(deps, env, info, mint, add, remove)
+        },
+        ExecuteMsg::ClaimVested { mint } => {
+            execute::claim_vested(deps, env, info, mint)
+        },
+        ExecuteMsg::SetModerator { moderator } => {
+            execute::set_moderator(deps, env, info, moderator)
+        },
+        ExecuteMsg::SetMasterNftContract { contract } => {
+            execute::set_master_nft_contract(deps, env, info, contract)
+        },
+        ExecuteMsg::RegisterAirdrop { mint, merkle_root, total_amount, expiry } => {
+            execute::register_airdrop(deps, env, info, mint, merkle_root, total_amount, expiry)
+        },
+        ExecuteMsg::ClaimAirdrop { mint, amount, proof } => {
+            execute::claim_airdrop(deps, env, info, mint, amount, proof)
+        },
+        ExecuteMsg::ReclaimAirdrop { mint } => {
+            execute::reclaim_airdrop(deps, env, info, mint)
+        },
+        ExecuteMsg::ClaimHolderRewards { mint } => {
+            execute::claim_holder_rewards(deps, env, info, mint)
+        },
+        ExecuteMsg::Stake { mint } => {
+            execute::stake(deps, env, info, mint)
+        },
+        ExecuteMsg::Unstake { mint, amount } => {
+            execute::unstake(deps, env, info, mint, amount)
+        },
+        ExecuteMsg::WithdrawUnbonded { mint } => {
+            execute::withdraw_unbonded(deps, env, info, mint)
+        },
+        ExecuteMsg::ClaimStakingRewards { mint } => {
+            execute::claim_staking_rewards(deps, env, info, mint)
+        },
+        ExecuteMsg::ClaimFees { denom } => {
+            execute::claim_fees(deps, env, info, denom)
+        },
+        ExecuteMsg::AddQuoteToken { token, inj_rate } => {
+            execute::add_quote_token(deps, env, info, token, inj_rate)
+        },
+        ExecuteMsg::RemoveQuoteToken { token } => {
+            execute::remove_quote_token(deps, env, info, token)
+        },
+        ExecuteMsg::RecoverTokens { asset, amount, recipient } => {
+            execute::recover_tokens(deps, env, info, asset, amount, recipient)
         },
         ExecuteMsg::MintToken { mint, recipient, amount } => {
             execute::mint_token(deps, env, info, mint, recipient, amount)
         },
+        ExecuteMsg::CompleteGraduation { mint, dex_pool } => {
+            execute::complete_graduation(deps, env, info, mint, dex_pool)
+        },
+        ExecuteMsg::DelistToken { mint, reason_uri } => {
+            execute::delist_token(deps, env, info, mint, reason_uri)
+        },
+        ExecuteMsg::RedeemDelisted { mint } => {
+            execute::redeem_delisted(deps, env, info, mint)
+        },
+        ExecuteMsg::PlaceHold { mint, reason_uri } => {
+            execute::place_hold(deps, env, info, mint, reason_uri)
+        },
+        ExecuteMsg::ResolveHold { mint, outcome, resolution_uri } => {
+            execute::resolve_hold(deps, env, info, mint, outcome, resolution_uri)
+        },
         ExecuteMsg::Receive(cw20_msg) => {
             execute::receive_cw20(deps, env, info, cw20_msg)
         },
@@ -70,11 +266,73 @@ pub fn execute(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
-    match msg {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+    let response = match msg {
         QueryMsg::GetPlatformConfig {} => to_json_binary(&query::get_platform_config(deps)?),
         QueryMsg::GetTokenMetadata { mint } => to_json_binary(&query::get_token_metadata(deps, mint)?),
+        QueryMsg::GetMetadataHistory { mint, start_after, limit } => {
+            to_json_binary(&query::get_metadata_history(deps, mint, start_after, limit)?)
+        },
+        QueryMsg::VerifyContent { mint, hash } => to_json_binary(&query::verify_content(deps, mint, hash)?),
         QueryMsg::GetTokenPrice { mint } => to_json_binary(&query::get_token_price(deps, mint)?),
         QueryMsg::GetVaultBalance { mint } => to_json_binary(&query::get_vault_balance(deps, mint)?),
-    }
+        QueryMsg::ListTokens { start_after, limit } => {
+            to_json_binary(&query::list_tokens(deps, start_after, limit)?)
+        },
+        QueryMsg::GetTokenById { id } => to_json_binary(&query::get_token_by_id(deps, id)?),
+        QueryMsg::ListTokensByCreator { creator, start_after, limit } => {
+            to_json_binary(&query::list_tokens_by_creator(deps, creator, start_after, limit)?)
+        },
+        QueryMsg::SearchTokensByNamePrefix { prefix, start_after, limit } => {
+            to_json_binary(&query::search_tokens_by_name_prefix(deps, prefix, start_after, limit)?)
+        },
+        QueryMsg::SimulatePurchase { mint, amount_tokens } => {
+            to_json_binary(&query::simulate_purchase(deps, env, mint, amount_tokens)?)
+        },
+        QueryMsg::SimulatePurchaseExactIn { mint, funds } => {
+            to_json_binary(&query::simulate_purchase_exact_in(deps, env, mint, funds)?)
+        },
+        QueryMsg::GetTokenState { mint } => to_json_binary(&query::get_token_state(deps, env, mint)?),
+        QueryMsg::GetTokenStatus { mint } => to_json_binary(&query::get_token_status(deps, env, mint)?),
+        QueryMsg::GetTokenRoyalties { mint } => to_json_binary(&query::get_token_royalties(deps, mint)?),
+        QueryMsg::GetCreatorRoyalties { creator } => {
+            to_json_binary(&query::get_creator_royalties(deps, creator)?)
+        },
+        QueryMsg::GetRevenueSplit { mint } => to_json_binary(&query::get_revenue_split(deps, mint)?),
+        QueryMsg::GetClaimable { address } => to_json_binary(&query::get_claimable(deps, address)?),
+        QueryMsg::GetReferralStats { address } => {
+            to_json_binary(&query::get_referral_stats(deps, address)?)
+        },
+        QueryMsg::ListQuoteTokens {} => to_json_binary(&query::list_quote_tokens(deps)?),
+        QueryMsg::GetVesting { mint, address } => {
+            to_json_binary(&query::get_vesting(deps, env, mint, address)?)
+        },
+        QueryMsg::GetLaunchConfig { mint } => to_json_binary(&query::get_launch_config(deps, mint)?),
+        QueryMsg::GetPresaleAllocation { mint, address } => {
+            to_json_binary(&query::get_presale_allocation(deps, mint, address)?)
+        },
+        QueryMsg::GetAirdrop { mint } => to_json_binary(&query::get_airdrop(deps, mint)?),
+        QueryMsg::GetAirdropClaim { mint, address } => {
+            to_json_binary(&query::get_airdrop_claim(deps, mint, address)?)
+        },
+        QueryMsg::GetRewardPool { mint } => to_json_binary(&query::get_reward_pool(deps, mint)?),
+        QueryMsg::GetHolderRewards { mint, address } => {
+            to_json_binary(&query::get_holder_rewards(deps, mint, address)?)
+        },
+        QueryMsg::GetStakingPool { mint } => to_json_binary(&query::get_staking_pool(deps, mint)?),
+        QueryMsg::GetStakedPosition { mint, address } => {
+            to_json_binary(&query::get_staked_position(deps, mint, address)?)
+        },
+        QueryMsg::GetDelisting { mint } => to_json_binary(&query::get_delisting(deps, mint)?),
+        QueryMsg::GetDisputes { mint, start_after, limit } => {
+            to_json_binary(&query::get_disputes(deps, mint, start_after, limit)?)
+        },
+    }?;
+    
+    Ok(response)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    migrate::migrate(deps, env, msg)
 }
\ No newline at end of file
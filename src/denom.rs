@@ -0,0 +1,52 @@
+use std::fmt;
+
+use cosmwasm_std::{Addr, Api};
+use crate::error::ContractError;
+
+// Token Factory denom of the form factory/{creator}/{subdenom}
+#[derive(Clone, Debug, PartialEq)]
+pub struct FactoryDenom {
+    denom: String,
+    pub creator: Addr,
+    pub subdenom: String,
+}
+
+impl FactoryDenom {
+    // Parse a denom string and validate its creator as a bech32 address
+    pub fn parse(api: &dyn Api, denom: &str) -> Result<Self, ContractError> {
+        let mut parts = denom.splitn(3, '/');
+        let (prefix, creator, subdenom) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(prefix), Some(creator), Some(subdenom)) => (prefix, creator, subdenom),
+            _ => return Err(ContractError::InvalidDenom {}),
+        };
+        
+        if prefix != "factory" || creator.is_empty() || subdenom.is_empty() {
+            return Err(ContractError::InvalidDenom {});
+        }
+        
+        let creator = api
+            .addr_validate(creator)
+            .map_err(|_| ContractError::InvalidDenom {})?;
+        
+        Ok(FactoryDenom {
+            denom: denom.to_string(),
+            creator,
+            subdenom: subdenom.to_string(),
+        })
+    }
+    
+    pub fn as_str(&self) -> &str {
+        &self.denom
+    }
+    
+    // Storage key used by all per-token maps
+    pub fn key(&self) -> &[u8] {
+        self.denom.as_bytes()
+    }
+}
+
+impl fmt::Display for FactoryDenom {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.denom)
+    }
+}
@@ -1,4 +1,5 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
+use crate::state::TokenStatus;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -9,11 +10,125 @@ pub enum ContractError {
     #[error("Unauthorized")]
     Unauthorized {},
 
-    #[error("Name must be between 1 and 32 characters")]
-    NameTooLong {},
+    #[error("{field} must not be empty")]
+    EmptyField { field: String },
 
-    #[error("URI must be between 1 and 44 characters")]
-    UriTooLong {},
+    #[error("{field} must not exceed {max} characters")]
+    FieldTooLong { field: String, max: usize },
+
+    #[error("{field} must be an ipfs://, ar:// or https:// URI naming valid content")]
+    InvalidUri { field: String },
+
+    #[error("Invalid {field}")]
+    InvalidMetadata { field: String },
+
+    #[error("Content hash must be a 32-byte sha256 digest")]
+    InvalidContentHash {},
+
+    #[error("At most {max} links are allowed")]
+    TooManyLinks { max: usize },
+
+    #[error("Metadata of this token is frozen")]
+    MetadataFrozen {},
+
+    #[error("Update does not change any metadata")]
+    NoMetadataChanges {},
+
+    #[error("Token {mint} is already initialized")]
+    TokenAlreadyInitialized { mint: String },
+
+    #[error("Royalty must not exceed {max_bps} basis points")]
+    RoyaltyTooHigh { max_bps: u16 },
+
+    #[error("Split needs 1 to 10 unique recipients with positive weights summing to 10000 basis points")]
+    InvalidSplit {},
+
+    #[error("Revenue split can only be changed before the first purchase")]
+    SplitLocked {},
+
+    #[error("Launch settings can only be changed before the first purchase")]
+    LaunchLocked {},
+
+    #[error("Launch caps must be positive, and a presale needs a 32-byte Merkle root, a non-empty window ending at the start time, a discount of at most 10000 basis points and a positive cap")]
+    InvalidLaunchConfig {},
+
+    #[error("Token launch starts at {start_time}")]
+    LaunchNotStarted { start_time: u64 },
+
+    #[error("Only allowlisted addresses can buy during the allowlist phase")]
+    NotOnAllowlist {},
+
+    #[error("Invalid Merkle proof")]
+    InvalidMerkleProof {},
+
+    #[error("Purchase exceeds the presale limit of {max} tokens per address")]
+    ExceedsPresaleCap { max: Uint128 },
+
+    #[error("Purchase exceeds the launch limit of {max} tokens per address")]
+    ExceedsAddressCap { max: Uint128 },
+
+    #[error("Purchase exceeds the limit of {max} tokens per transaction")]
+    ExceedsTransactionCap { max: Uint128 },
+
+    #[error("Nothing to claim")]
+    NothingToClaim {},
+
+    #[error("Referral share must not exceed 10000 basis points")]
+    InvalidReferralShare {},
+
+    #[error("Referral, holder and staker reward shares must not exceed 10000 basis points combined")]
+    InvalidFeeShares {},
+
+    #[error("Cannot refer your own purchase")]
+    SelfReferral {},
+
+    #[error("CW20 token is not an accepted quote token")]
+    UnsupportedQuoteToken {},
+
+    #[error("Unrecognized CW20 receive message")]
+    InvalidReceiveMsg {},
+
+    #[error("Only {available} of this asset is not reserved and can be recovered")]
+    ExceedsRecoverable { available: Uint128 },
+
+    #[error("Vesting needs a positive amount and a duration no shorter than its cliff")]
+    InvalidVesting {},
+
+    #[error("Vesting allocation already exists for this token")]
+    VestingAlreadySet {},
+
+    #[error("No vesting schedule for {address} on {mint}")]
+    VestingNotFound { mint: String, address: String },
+
+    #[error("Airdrop needs a 32-byte Merkle root, a non-zero amount and a future expiry")]
+    InvalidAirdrop {},
+
+    #[error("Airdrop already registered for this token")]
+    AirdropAlreadyExists {},
+
+    #[error("No airdrop registered for {mint}")]
+    AirdropNotFound { mint: String },
+
+    #[error("Airdrop expired at {expiry}")]
+    AirdropExpired { expiry: u64 },
+
+    #[error("Airdrop is claimable until {expiry}")]
+    AirdropNotExpired { expiry: u64 },
+
+    #[error("Airdrop already claimed")]
+    AirdropAlreadyClaimed {},
+
+    #[error("Send only {denom} to stake")]
+    InvalidStakeFunds { denom: String },
+
+    #[error("No master NFT collection is configured")]
+    MasterNftNotConfigured {},
+
+    #[error("Only {staked} tokens are staked")]
+    InsufficientStake { staked: Uint128 },
+
+    #[error("Purchase would yield {amount_tokens} tokens, below the minimum of {min_tokens_out}")]
+    SlippageExceeded { min_tokens_out: Uint128, amount_tokens: Uint128 },
 
     #[error("Invalid amount provided")]
     InvalidAmount {},
@@ -21,6 +136,9 @@ pub enum ContractError {
     #[error("Insufficient funds for purchase")]
     InsufficientFunds {},
 
+    #[error("Expected a deposit of exactly {expected} {denom}")]
+    InvalidDeposit { expected: Uint128, denom: String },
+
     #[error("Supply calculation overflow")]
     SupplyOverflow {},
 
@@ -33,9 +151,33 @@ pub enum ContractError {
     #[error("Vault already setup for this token")]
     VaultAlreadySetup {},
 
-    #[error("Invalid denom format, must start with 'factory/'")]
+    #[error("Invalid denom format, expected factory/{{creator}}/{{subdenom}}")]
     InvalidDenom {},
 
+    #[error("Unknown token: {mint}")]
+    TokenNotFound { mint: String },
+
+    #[error("Token {mint} is not initialized yet: missing {missing}")]
+    TokenNotInitialized { mint: String, missing: String },
+
+    #[error("Token id {id} is already used by another token")]
+    TokenIdTaken { id: u64 },
+
+    #[error("Token cannot move from {from} to {to}")]
+    InvalidStatusTransition { from: TokenStatus, to: TokenStatus },
+
+    #[error("Token {mint} is not open for trading while {status}")]
+    TokenNotTradable { mint: String, status: TokenStatus },
+
+    #[error("Not allowed while the token is {status}")]
+    InvalidTokenStatus { status: TokenStatus },
+
+    #[error("Send only {denom} to redeem")]
+    InvalidRedeemFunds { denom: String },
+
+    #[error("Only {remaining} more tokens can be redeemed")]
+    ExceedsRedeemableSupply { remaining: Uint128 },
+
     #[error("Custom Error val: {val:?}")]
     CustomError { val: String },
 }
\ No newline at end of file
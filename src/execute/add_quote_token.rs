@@ -0,0 +1,32 @@
+use cosmwasm_std::{Decimal, DepsMut, Env, MessageInfo, Response};
+use crate::{
+    error::ContractError,
+    state::{CONFIG, QUOTE_TOKENS}
+};
+
+pub fn add_quote_token(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token: String,
+    inj_rate: Decimal,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    
+    // Only the authority manages accepted quote tokens
+    if info.sender != config.authority {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    if inj_rate.is_zero() {
+        return Err(ContractError::InvalidAmount {});
+    }
+    
+    let token_addr = deps.api.addr_validate(&token)?;
+    QUOTE_TOKENS.save(deps.storage, &token_addr, &inj_rate)?;
+    
+    Ok(Response::new()
+        .add_attribute("action", "add_quote_token")
+        .add_attribute("token", token_addr)
+        .add_attribute("inj_rate", inj_rate.to_string()))
+}
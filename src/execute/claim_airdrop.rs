@@ -0,0 +1,59 @@
+use cosmwasm_std::{coins, BankMsg, DepsMut, Env, HexBinary, MessageInfo, Response, Uint128};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    state::{add_holdings, assert_not_on_hold, AIRDROPS, AIRDROP_CLAIMS},
+    utils::{airdrop_leaf, verify_merkle_proof}
+};
+
+pub fn claim_airdrop(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mint: String,
+    amount: Uint128,
+    proof: Vec<HexBinary>,
+) -> Result<Response, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    assert_not_on_hold(deps.storage, &denom)?;
+    let mut airdrop = AIRDROPS
+        .may_load(deps.storage, denom.key())?
+        .ok_or_else(|| ContractError::AirdropNotFound { mint: mint.clone() })?;
+    
+    if env.block.time.seconds() >= airdrop.expiry {
+        return Err(ContractError::AirdropExpired { expiry: airdrop.expiry });
+    }
+    
+    // Each allocation can be claimed once
+    let key = (denom.key(), &info.sender);
+    if AIRDROP_CLAIMS.has(deps.storage, key) {
+        return Err(ContractError::AirdropAlreadyClaimed {});
+    }
+    
+    if !verify_merkle_proof(&airdrop.merkle_root, airdrop_leaf(&info.sender, amount), &proof) {
+        return Err(ContractError::InvalidMerkleProof {});
+    }
+    
+    // A tree that allocates more than was deposited cannot drain other reserves
+    let claimed_amount = airdrop.claimed_amount + amount;
+    if amount.is_zero() || claimed_amount > airdrop.total_amount {
+        return Err(ContractError::InvalidAmount {});
+    }
+    
+    airdrop.claimed_amount = claimed_amount;
+    AIRDROPS.save(deps.storage, denom.key(), &airdrop)?;
+    AIRDROP_CLAIMS.save(deps.storage, key, &amount)?;
+    add_holdings(deps.storage, denom.key(), &info.sender, amount)?;
+    
+    let send_msg = BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: coins(amount.u128(), denom.as_str()),
+    };
+    
+    Ok(Response::new()
+        .add_message(send_msg)
+        .add_attribute("action", "claim_airdrop")
+        .add_attribute("mint", mint)
+        .add_attribute("recipient", info.sender)
+        .add_attribute("amount", amount.to_string()))
+}
@@ -0,0 +1,33 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdResult};
+use crate::{
+    asset::PaymentAsset,
+    error::ContractError,
+    state::{CLAIMABLE, TOTAL_CLAIMABLE}
+};
+
+pub fn claim_fees(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    denom: String,
+) -> Result<Response, ContractError> {
+    let asset = PaymentAsset::from_ledger_key(deps.api, &denom)?;
+    
+    let key = (&info.sender, denom.as_str());
+    let amount = CLAIMABLE.may_load(deps.storage, key)?.unwrap_or_default();
+    if amount.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+    
+    CLAIMABLE.remove(deps.storage, key);
+    TOTAL_CLAIMABLE.update(deps.storage, &denom, |total| -> StdResult<_> {
+        Ok(total.unwrap_or_default().checked_sub(amount)?)
+    })?;
+    
+    Ok(Response::new()
+        .add_message(asset.transfer_msg(&info.sender, amount)?)
+        .add_attribute("action", "claim_fees")
+        .add_attribute("recipient", info.sender)
+        .add_attribute("denom", denom)
+        .add_attribute("amount", amount.to_string()))
+}
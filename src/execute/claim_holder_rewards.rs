@@ -0,0 +1,41 @@
+use cosmwasm_std::{coins, BankMsg, DepsMut, Env, MessageInfo, Response, StdResult, Uint128};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    state::{assert_not_on_hold, load_holder_rewards, HOLDER_REWARDS, HOLDER_REWARDS_OUTSTANDING, REWARD_POOLS}
+};
+
+pub fn claim_holder_rewards(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    mint: String,
+) -> Result<Response, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    assert_not_on_hold(deps.storage, &denom)?;
+    let pool = REWARD_POOLS.may_load(deps.storage, denom.key())?.unwrap_or_default();
+    
+    let mut rewards = load_holder_rewards(deps.storage, denom.key(), &info.sender, &pool)?;
+    let amount = rewards.accrued;
+    if amount.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+    
+    rewards.accrued = Uint128::zero();
+    HOLDER_REWARDS.save(deps.storage, (denom.key(), &info.sender), &rewards)?;
+    HOLDER_REWARDS_OUTSTANDING.update(deps.storage, |total| -> StdResult<_> {
+        Ok(total.checked_sub(amount)?)
+    })?;
+    
+    let send_msg = BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: coins(amount.u128(), "inj"),
+    };
+    
+    Ok(Response::new()
+        .add_message(send_msg)
+        .add_attribute("action", "claim_holder_rewards")
+        .add_attribute("mint", mint)
+        .add_attribute("recipient", info.sender)
+        .add_attribute("amount", amount.to_string()))
+}
@@ -0,0 +1,41 @@
+use cosmwasm_std::{coins, BankMsg, DepsMut, Env, MessageInfo, Response, StdResult, Uint128};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    state::{assert_not_on_hold, load_staker, STAKERS, STAKING_POOLS, STAKING_REWARDS_OUTSTANDING}
+};
+
+pub fn claim_staking_rewards(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    mint: String,
+) -> Result<Response, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    assert_not_on_hold(deps.storage, &denom)?;
+    let pool = STAKING_POOLS.may_load(deps.storage, denom.key())?.unwrap_or_default();
+    
+    let mut staker = load_staker(deps.storage, denom.key(), &info.sender, &pool)?;
+    let amount = staker.accrued;
+    if amount.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+    
+    staker.accrued = Uint128::zero();
+    STAKERS.save(deps.storage, (denom.key(), &info.sender), &staker)?;
+    STAKING_REWARDS_OUTSTANDING.update(deps.storage, |total| -> StdResult<_> {
+        Ok(total.checked_sub(amount)?)
+    })?;
+    
+    let send_msg = BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: coins(amount.u128(), "inj"),
+    };
+    
+    Ok(Response::new()
+        .add_message(send_msg)
+        .add_attribute("action", "claim_staking_rewards")
+        .add_attribute("mint", mint)
+        .add_attribute("recipient", info.sender)
+        .add_attribute("amount", amount.to_string()))
+}
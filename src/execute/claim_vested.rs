@@ -0,0 +1,44 @@
+use cosmwasm_std::{coins, BankMsg, DepsMut, Env, MessageInfo, Response};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    state::{add_holdings, assert_not_on_hold, VESTING},
+    utils::vested_amount
+};
+
+pub fn claim_vested(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mint: String,
+) -> Result<Response, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    assert_not_on_hold(deps.storage, &denom)?;
+    let key = (denom.key(), &info.sender);
+    
+    let mut schedule = VESTING
+        .may_load(deps.storage, key)?
+        .ok_or(ContractError::NothingToClaim {})?;
+    
+    let vested = vested_amount(&schedule, env.block.time.seconds());
+    let amount = vested - schedule.claimed;
+    if amount.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+    
+    schedule.claimed = vested;
+    VESTING.save(deps.storage, key, &schedule)?;
+    add_holdings(deps.storage, denom.key(), &info.sender, amount)?;
+    
+    let send_msg = BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: coins(amount.u128(), denom.as_str()),
+    };
+    
+    Ok(Response::new()
+        .add_message(send_msg)
+        .add_attribute("action", "claim_vested")
+        .add_attribute("mint", mint)
+        .add_attribute("recipient", info.sender)
+        .add_attribute("amount", amount.to_string()))
+}
@@ -0,0 +1,42 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    state::{load_token_status, load_vault, set_token_status, TokenStatus, CONFIG, TOKEN_VAULTS}
+};
+
+pub fn complete_graduation(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    mint: String,
+    dex_pool: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    
+    // Only the authority moves liquidity to a DEX
+    if info.sender != config.authority {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let mut vault = load_vault(deps.storage, &denom)?;
+    let dex_pool = deps.api.addr_validate(&dex_pool)?;
+    
+    // Only a graduating token can complete graduation
+    let status = load_token_status(deps.storage, &denom)?;
+    if status != TokenStatus::Graduating {
+        return Err(ContractError::InvalidTokenStatus { status });
+    }
+    let status_event = set_token_status(deps.storage, &denom, TokenStatus::Graduated)?;
+    
+    vault.dex_pool = Some(dex_pool.clone());
+    TOKEN_VAULTS.save(deps.storage, denom.key(), &vault)?;
+    
+    Ok(Response::new()
+        .add_events(status_event)
+        .add_attribute("action", "complete_graduation")
+        .add_attribute("mint", mint)
+        .add_attribute("dex_pool", dex_pool)
+        .add_attribute("status", TokenStatus::Graduated.as_str()))
+}
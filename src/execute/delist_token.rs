@@ -0,0 +1,127 @@
+use cosmwasm_std::{Decimal256, DepsMut, Env, Event, MessageInfo, Order, Response, StdResult, Storage, Uint128};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    metadata::validate_uri,
+    state::{
+        resolve_open_dispute, set_token_status, Delisting, DisputeResolution, HoldOutcome, TokenStatus,
+        AIRDROPS, CONFIG, DELISTINGS, REDEMPTION_RESERVES_OUTSTANDING, REWARD_POOLS, TOKEN_SUPPLY,
+        TOKEN_VAULTS, VESTING
+    }
+};
+
+// Halt a token and fix its redemption rate from its reserve over the circulating supply.
+// `deposit` is the INJ returned from the vault wallet and must equal the INJ it collected.
+pub(crate) fn delist(
+    storage: &mut dyn Storage,
+    denom: &FactoryDenom,
+    deposit: Uint128,
+    reason_uri: Option<String>,
+    now: u64,
+) -> Result<(Delisting, Option<Event>), ContractError> {
+    if let Some(uri) = &reason_uri {
+        validate_uri("reason_uri", uri)?;
+    }
+    
+    // Redemptions pay INJ, so the reserve is the INJ the vault collected; CW20 payments stay
+    // with the vault wallet
+    let reserve = TOKEN_VAULTS
+        .may_load(storage, denom.key())?
+        .map(|vault| vault.inj_collected())
+        .unwrap_or_default();
+    if deposit != reserve {
+        return Err(ContractError::InvalidDeposit { expected: reserve, denom: "inj".to_string() });
+    }
+    
+    let status_event = set_token_status(storage, denom, TokenStatus::Delisted)?;
+    if status_event.is_none() {
+        return Err(ContractError::InvalidTokenStatus { status: TokenStatus::Delisted });
+    }
+    
+    // Supply issued through purchases and mints, plus the creator's pre-mine and airdrop deposits
+    let issued = TOKEN_SUPPLY.may_load(storage, denom.key())?.unwrap_or_default();
+    let vesting = VESTING
+        .prefix(denom.key())
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, schedule)| schedule.total))
+        .sum::<StdResult<Uint128>>()?;
+    let airdrop = AIRDROPS
+        .may_load(storage, denom.key())?
+        .map(|airdrop| airdrop.total_amount)
+        .unwrap_or_default();
+    let supply = issued + vesting + airdrop;
+    let rate = if supply.is_zero() {
+        Decimal256::zero()
+    } else {
+        Decimal256::from_ratio(reserve, supply)
+    };
+    
+    let delisting = Delisting {
+        reason_uri,
+        delisted_at: now,
+        reserve,
+        supply,
+        rate,
+        redeemed: Uint128::zero(),
+        paid_out: Uint128::zero(),
+    };
+    DELISTINGS.save(storage, denom.key(), &delisting)?;
+    
+    let outstanding = REDEMPTION_RESERVES_OUTSTANDING.may_load(storage)?.unwrap_or_default();
+    REDEMPTION_RESERVES_OUTSTANDING.save(storage, &(outstanding + reserve))?;
+    
+    // Views no longer update, so hand holders the rewards still waiting on them
+    if let Some(mut pool) = REWARD_POOLS.may_load(storage, denom.key())? {
+        pool.release();
+        REWARD_POOLS.save(storage, denom.key(), &pool)?;
+    }
+    
+    Ok((delisting, status_event))
+}
+
+pub fn delist_token(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mint: String,
+    reason_uri: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    
+    // Only the authority takes songs down
+    if info.sender != config.authority {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    
+    // The vault's collected INJ is returned with this message to back redemptions
+    let deposit = info
+        .funds
+        .iter()
+        .find(|c| c.denom == "inj")
+        .map(|c| c.amount)
+        .unwrap_or_default();
+    
+    let now = env.block.time.seconds();
+    let (delisting, status_event) = delist(deps.storage, &denom, deposit, reason_uri.clone(), now)?;
+    
+    // Delisting a held token settles its dispute
+    let resolution = DisputeResolution {
+        outcome: HoldOutcome::Delist,
+        resolved_by: info.sender,
+        resolved_at: now,
+        resolution_uri: reason_uri,
+    };
+    let resolved_event = resolve_open_dispute(deps.storage, denom.key(), resolution)?
+        .map(|dispute| super::resolve_hold::resolved_event(&mint, &dispute));
+    
+    Ok(Response::new()
+        .add_events(status_event)
+        .add_events(resolved_event)
+        .add_attribute("action", "delist_token")
+        .add_attribute("mint", mint)
+        .add_attribute("reserve", delisting.reserve.to_string())
+        .add_attribute("supply", delisting.supply.to_string())
+        .add_attribute("rate", delisting.rate.to_string()))
+}
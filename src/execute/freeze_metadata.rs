@@ -0,0 +1,38 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    nft::assert_rights_holder,
+    state::{load_token_metadata, record_metadata_change, token_metadata}
+};
+
+pub fn freeze_metadata(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mint: String,
+) -> Result<Response, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let mut metadata = load_token_metadata(deps.storage, &denom)?;
+    
+    assert_rights_holder(&deps.querier, &metadata, &info.sender)?;
+    if metadata.frozen {
+        return Err(ContractError::MetadataFrozen {});
+    }
+    
+    // Freezing cannot be undone
+    metadata.frozen = true;
+    token_metadata().save(deps.storage, denom.key(), &metadata)?;
+    record_metadata_change(
+        deps.storage,
+        denom.key(),
+        "freeze",
+        &info.sender,
+        env.block.time.seconds(),
+        vec![],
+    )?;
+    
+    Ok(Response::new()
+        .add_attribute("action", "freeze_metadata")
+        .add_attribute("mint", mint))
+}
@@ -1,43 +1,166 @@
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
-use crate::{error::ContractError, state::{TOKEN_METADATA, TokenMetadata}};
+use cosmwasm_std::{DepsMut, Env, HexBinary, MessageInfo, Response, Uint128};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::VestingParams,
+    state::{
+        record_metadata_change, token_metadata, FieldChange, MasterNft, SongDetails, TokenMetadata,
+        TokenStatus, VestingSchedule, CONFIG, TOKEN_STATUS, VESTING
+    },
+    metadata::{validate_content_hash, validate_name, validate_uri},
+    utils::MAX_ROYALTY_BPS
+};
 
+#[allow(clippy::too_many_arguments)]
 pub fn initialize_token_metadata(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     id: u64,
     name: String,
     music_uri: String,
+    content_hash: Option<HexBinary>,
     mint: String,
+    creator: Option<String>,
+    royalty_bps: u16,
+    vesting: Option<VestingParams>,
+    mint_master_nft: Option<bool>,
+    details: Option<SongDetails>,
 ) -> Result<Response, ContractError> {
     // Validate inputs
-    if name.len() > 32 {
-        return Err(ContractError::NameTooLong {});
+    validate_name(&name)?;
+    validate_uri("music_uri", &music_uri)?;
+    if let Some(hash) = &content_hash {
+        validate_content_hash(hash)?;
     }
+    let details = details.unwrap_or_default();
+    details.validate()?;
     
-    if music_uri.len() > 44 {
-        return Err(ContractError::UriTooLong {});
+    if royalty_bps > MAX_ROYALTY_BPS {
+        return Err(ContractError::RoyaltyTooHigh { max_bps: MAX_ROYALTY_BPS });
     }
     
     // Validate mint is a proper token factory denom
-    if !mint.starts_with("factory/") {
-        return Err(ContractError::InvalidDenom {});
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    
+    // Only the denom's creator registers it and picks its royalty recipient
+    if info.sender != denom.creator {
+        return Err(ContractError::Unauthorized {});
     }
     
+    // Royalties go to the denom creator unless another artist address is given
+    let creator = match creator {
+        Some(creator) => deps.api.addr_validate(&creator)?,
+        None => denom.creator.clone(),
+    };
+    
     // Use the denom string as the key
-    let key = mint.as_bytes();
+    let key = denom.key();
+    
+    // Metadata is written once; later edits go through UpdateTokenMetadata
+    let tokens = token_metadata();
+    if tokens.has(deps.storage, key) {
+        return Err(ContractError::TokenAlreadyInitialized { mint });
+    }
+    
+    // Song ids must be unique across tokens
+    if tokens.idx.id.item(deps.storage, id)?.is_some() {
+        return Err(ContractError::TokenIdTaken { id });
+    }
+    
+    // The master NFT is keyed by the denom in the platform's collection
+    let master_nft = if mint_master_nft.unwrap_or(false) {
+        let contract = CONFIG
+            .load(deps.storage)?
+            .master_nft_contract
+            .ok_or(ContractError::MasterNftNotConfigured {})?;
+        Some(MasterNft { contract, token_id: mint.clone() })
+    } else {
+        None
+    };
     
     let metadata = TokenMetadata {
         name,
         music_uri,
+        content_hash,
         id,
         mint: mint.clone(), // Store the denom string
+        creator: creator.clone(),
+        royalty_bps,
+        master_nft: master_nft.clone(),
+        details,
+        frozen: false,
     };
     
-    TOKEN_METADATA.save(deps.storage, key, &metadata)?;
+    tokens.save(deps.storage, key, &metadata)?;
+    TOKEN_STATUS.save(deps.storage, key, &TokenStatus::Draft)?;
+    let mut changes = vec![
+        FieldChange { field: "name".to_string(), old: None, new: Some(metadata.name.clone()) },
+        FieldChange { field: "music_uri".to_string(), old: None, new: Some(metadata.music_uri.clone()) },
+    ];
+    if let Some(hash) = &metadata.content_hash {
+        changes.push(FieldChange {
+            field: "content_hash".to_string(),
+            old: None,
+            new: Some(hash.to_hex()),
+        });
+    }
+    changes.extend(metadata.details.changes_from(&SongDetails::default()));
+    record_metadata_change(deps.storage, key, "initialize", &info.sender, env.block.time.seconds(), changes)?;
+    
+    // Hold the creator's pre-mine and release it on a linear schedule after the cliff
+    let mut vesting_amount = Uint128::zero();
+    if let Some(params) = vesting {
+        if params.amount.is_zero()
+            || params.duration_seconds == 0
+            || params.duration_seconds < params.cliff_seconds
+        {
+            return Err(ContractError::InvalidVesting {});
+        }
+        
+        if VESTING.has(deps.storage, (key, &creator)) {
+            return Err(ContractError::VestingAlreadySet {});
+        }
+        
+        // The pre-mine must be deposited with this message
+        let deposited = info
+            .funds
+            .iter()
+            .find(|c| c.denom == mint)
+            .map(|c| c.amount)
+            .unwrap_or_default();
+        if deposited != params.amount {
+            return Err(ContractError::InvalidDeposit {
+                expected: params.amount,
+                denom: mint,
+            });
+        }
+        
+        let start_time = env.block.time.seconds();
+        let schedule = VestingSchedule {
+            total: params.amount,
+            claimed: Uint128::zero(),
+            start_time,
+            cliff_time: start_time + params.cliff_seconds,
+            end_time: start_time + params.duration_seconds,
+        };
+        VESTING.save(deps.storage, (key, &creator), &schedule)?;
+        vesting_amount = params.amount;
+    }
+    
+    let mut response = Response::new();
+    if let Some(nft) = &master_nft {
+        response = response
+            .add_message(nft.mint_msg(&creator, &metadata.music_uri)?)
+            .add_attribute("master_nft", &nft.token_id);
+    }
     
-    Ok(Response::new()
+    Ok(response
         .add_attribute("action", "initialize_token_metadata")
         .add_attribute("id", id.to_string())
-        .add_attribute("mint", mint))
+        .add_attribute("mint", mint)
+        .add_attribute("creator", creator)
+        .add_attribute("royalty_bps", royalty_bps.to_string())
+        .add_attribute("vesting_amount", vesting_amount.to_string())
+        .add_attribute("status", TokenStatus::Draft.as_str()))
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
 use crate::{
+    denom::FactoryDenom,
     error::ContractError,
     state::{VIEWERSHIP_ORACLES, TOKEN_VAULTS, ViewershipOracle, PriceParameters, TokenVault}
 };
@@ -11,11 +12,13 @@ pub fn initialize_token_oracle(
     mint: String,
 ) -> Result<Response, ContractError> {
     // Validate mint is a proper token factory denom
-    if !mint.starts_with("factory/") {
-        return Err(ContractError::InvalidDenom {});
-    }
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let mint_key = denom.key();
     
-    let mint_key = mint.as_bytes();
+    // Only the denom's creator sets up its oracle and vault
+    if info.sender != denom.creator {
+        return Err(ContractError::Unauthorized {});
+    }
     
     // Check if oracle already exists
     if VIEWERSHIP_ORACLES.may_load(deps.storage, mint_key)?.is_some() {
@@ -43,6 +46,7 @@ pub fn initialize_token_oracle(
         liquidity_threshold: Uint128::from(10_000_000_000u128), // 10 INJ in smallest units
         total_collected: Uint128::zero(),
         dex_pool: None,
+        native_collected: Some(Uint128::zero()),
     };
     
     TOKEN_VAULTS.save(deps.storage, mint_key, &vault)?;
@@ -0,0 +1,148 @@
+use cosmwasm_std::{
+    coins, BankMsg, DepsMut, Env, Event, HexBinary, MessageInfo, Response, Uint128
+};
+use crate::{
+    asset::PaymentAsset,
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::LaunchParams,
+    state::{credit_claimable, SongDetails, CONFIG},
+    tokenfactory::{create_denom_msg, set_denom_metadata_msg, DenomMetadata},
+    utils::quote_at_price
+};
+use super::{
+    initialize_token_metadata, initialize_token_oracle, setup_vault_account,
+    purchase_token::{load_purchase_context, settle_purchase},
+    set_launch_config::{build_launch_config, save_launch_config}
+};
+
+// Fold a setup step's response into the launch response, its attributes as their own event
+fn add_step(response: Response, step: &str, result: Response) -> Response {
+    response
+        .add_submessages(result.messages)
+        .add_event(Event::new(step).add_attributes(result.attributes))
+        .add_events(result.events)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn launch_token(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    subdenom: String,
+    id: u64,
+    name: String,
+    music_uri: String,
+    content_hash: Option<HexBinary>,
+    creator: Option<String>,
+    royalty_bps: u16,
+    mint_master_nft: Option<bool>,
+    details: Option<SongDetails>,
+    launch: Option<LaunchParams>,
+    initial_buy: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    // The denom is named for the contract, which creates it and is its admin
+    let mint = format!("factory/{}/{}", env.contract.address, subdenom);
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let config = CONFIG.load(deps.storage)?;
+    
+    // Check launch settings up front so a bad config fails before anything is written
+    let launch = launch.map(build_launch_config).transpose()?;
+    
+    let payment = info
+        .funds
+        .iter()
+        .find(|c| c.denom == "inj")
+        .map(|c| c.amount)
+        .unwrap_or_default();
+    if payment < config.launch_fee {
+        return Err(ContractError::InsufficientFunds {});
+    }
+    
+    // Setup steps run as the contract, the denom's creator, without funds; the INJ is accounted for here
+    let setup_info = MessageInfo { sender: env.contract.address.clone(), funds: vec![] };
+    let creator = creator.unwrap_or_else(|| info.sender.to_string());
+    
+    // Create the denom and describe it in the bank module before anything else is sent
+    let song = details.clone().unwrap_or_default();
+    let symbol = song.symbol.unwrap_or_else(|| subdenom.to_uppercase());
+    let denom_metadata = DenomMetadata {
+        description: song.description.unwrap_or_default(),
+        base: mint.clone(),
+        name: name.clone(),
+        symbol: symbol.clone(),
+        uri: music_uri.clone(),
+        uri_hash: content_hash.as_ref().map(HexBinary::to_hex).unwrap_or_default(),
+    };
+    let mut response = Response::new()
+        .add_message(create_denom_msg(&env.contract.address, &subdenom, &name, &symbol))
+        .add_message(set_denom_metadata_msg(&env.contract.address, &denom_metadata));
+    
+    let result = initialize_token_metadata(
+        deps.branch(),
+        env.clone(),
+        setup_info.clone(),
+        id,
+        name,
+        music_uri,
+        content_hash,
+        mint.clone(),
+        Some(creator),
+        royalty_bps,
+        None,
+        mint_master_nft,
+        details,
+    )?;
+    response = add_step(response, "initialize_token_metadata", result);
+    
+    let result = initialize_token_oracle(deps.branch(), env.clone(), setup_info.clone(), mint.clone())?;
+    response = add_step(response, "initialize_token_oracle", result);
+    
+    let result = setup_vault_account(deps.branch(), env.clone(), setup_info, mint.clone())?;
+    response = add_step(response, "setup_vault_account", result);
+    
+    // Accrue the launch fee for the platform wallet to claim
+    credit_claimable(deps.storage, &config.platform_wallet, "inj", config.launch_fee)?;
+    
+    // The creator's initial buy is exempt from the launch rules: it settles before the launch
+    // config is saved, so the start time, allowlist and caps do not limit it
+    let mut spent = config.launch_fee;
+    if let Some(amount_tokens) = initial_buy.filter(|amount| !amount.is_zero()) {
+        let mut ctx = load_purchase_context(deps.storage, &env, denom.clone(), &info.sender, None)?;
+    
+        // The master NFT is only minted once this message succeeds; until then the creator holds its rights
+        ctx.metadata.master_nft = None;
+    
+        let quote = quote_at_price(ctx.price_per_token, amount_tokens, ctx.metadata.royalty_bps)?;
+        spent += quote.total_cost;
+        if payment < spent {
+            return Err(ContractError::InsufficientFunds {});
+        }
+    
+        let asset = PaymentAsset::Native("inj".to_string());
+        let result = settle_purchase(deps.branch(), ctx, &info.sender, None, &asset, &quote, &quote)?;
+        response = add_step(response, "initial_buy", result);
+    }
+    
+    if let Some(launch) = &launch {
+        let status_event = save_launch_config(deps.storage, &denom, launch, env.block.time.seconds())?;
+        response = response.add_events(status_event);
+    }
+    
+    // Return INJ sent beyond the launch fee and the initial buy
+    let refund = payment - spent;
+    if !refund.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: coins(refund.u128(), "inj"),
+        });
+    }
+    
+    Ok(response
+        .add_attribute("action", "launch_token")
+        .add_attribute("create_denom", subdenom)
+        .add_attribute("mint", mint)
+        .add_attribute("launch_fee", config.launch_fee.to_string())
+        .add_attribute("initial_buy", initial_buy.unwrap_or_default().to_string())
+        .add_attribute("refund", refund.to_string()))
+}
@@ -1,17 +1,10 @@
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128, WasmMsg, to_json_binary};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
 use crate::{
+    denom::FactoryDenom,
     error::ContractError,
-    state::{TOKEN_METADATA, TOKEN_VAULTS, CONFIG}
+    state::{add_holdings, load_token_metadata, load_token_status, load_vault, TokenStatus, TOKEN_SUPPLY, CONFIG}
 };
 
-// Custom message structure to be passed to a Token Factory handler contract
-#[derive(serde::Serialize)]
-struct MintMsg {
-    denom: String,
-    amount: String,
-    recipient: String,
-}
-
 pub fn mint_token(
     deps: DepsMut,
     _env: Env,
@@ -26,15 +19,12 @@ pub fn mint_token(
     }
     
     // Validate mint is a proper token factory denom
-    if !mint.starts_with("factory/") {
-        return Err(ContractError::InvalidDenom {});
-    }
-    
-    let mint_key = mint.as_bytes();
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let mint_key = denom.key();
     
     // Verify token metadata and vault exist
-    let _metadata = TOKEN_METADATA.load(deps.storage, mint_key)?;
-    let _vault = TOKEN_VAULTS.load(deps.storage, mint_key)?;
+    let _metadata = load_token_metadata(deps.storage, &denom)?;
+    let _vault = load_vault(deps.storage, &denom)?;
     let config = CONFIG.load(deps.storage)?;
     
     // Verify caller is authorized to mint
@@ -42,9 +32,24 @@ pub fn mint_token(
         return Err(ContractError::Unauthorized {});
     }
     
+    // No new supply for tokens that are not set up, mid-graduation, held or delisted
+    let status = load_token_status(deps.storage, &denom)?;
+    if matches!(
+        status,
+        TokenStatus::Draft | TokenStatus::Graduating | TokenStatus::Delisted | TokenStatus::OnHold
+    ) {
+        return Err(ContractError::InvalidTokenStatus { status });
+    }
+    
     // Validate recipient
     let recipient_addr = deps.api.addr_validate(&recipient)?;
     
+    // Track issued supply
+    let supply = TOKEN_SUPPLY.may_load(deps.storage, mint_key)?.unwrap_or_default();
+    let new_supply = supply.checked_add(amount).map_err(|_| ContractError::SupplyOverflow {})?;
+    TOKEN_SUPPLY.save(deps.storage, mint_key, &new_supply)?;
+    add_holdings(deps.storage, mint_key, &recipient_addr, amount)?;
+    
     // In a real implementation, you'd interact with Injective's token factory
     // For now, we'll just record the intent in the response
     
@@ -6,6 +6,32 @@ mod update_oracle;
 mod purchase_token;
 mod receive_cw20;
 mod mint_token; 
+mod set_revenue_split;
+mod claim_fees;
+mod add_quote_token;
+mod remove_quote_token;
+mod recover_tokens;
+mod claim_vested;
+mod set_launch_config;
+mod update_launch_allowlist;
+mod register_airdrop;
+mod claim_airdrop;
+mod reclaim_airdrop;
+mod claim_holder_rewards;
+mod stake;
+mod unstake;
+mod withdraw_unbonded;
+mod claim_staking_rewards;
+mod set_master_nft_contract;
+mod update_token_metadata;
+mod freeze_metadata;
+mod launch_token;
+mod complete_graduation;
+mod delist_token;
+mod redeem_delisted;
+mod set_moderator;
+mod place_hold;
+mod resolve_hold;
 
 
 pub use update_platform::update_platform;
@@ -15,4 +41,30 @@ pub use setup_vault_account::setup_vault_account;
 pub use update_oracle::update_oracle;
 pub use purchase_token::purchase_token;
 pub use receive_cw20::receive_cw20;
-pub use mint_token::mint_token;
\ No newline at end of file
+pub use mint_token::mint_token;
+pub use set_revenue_split::set_revenue_split;
+pub use claim_fees::claim_fees;
+pub use add_quote_token::add_quote_token;
+pub use remove_quote_token::remove_quote_token;
+pub use recover_tokens::recover_tokens;
+pub use claim_vested::claim_vested;
+pub use set_launch_config::set_launch_config;
+pub use update_launch_allowlist::update_launch_allowlist;
+pub use register_airdrop::register_airdrop;
+pub use claim_airdrop::claim_airdrop;
+pub use reclaim_airdrop::reclaim_airdrop;
+pub use claim_holder_rewards::claim_holder_rewards;
+pub use stake::stake;
+pub use unstake::unstake;
+pub use withdraw_unbonded::withdraw_unbonded;
+pub use claim_staking_rewards::claim_staking_rewards;
+pub use set_master_nft_contract::set_master_nft_contract;
+pub use update_token_metadata::update_token_metadata;
+pub use freeze_metadata::freeze_metadata;
+pub use launch_token::launch_token;
+pub use complete_graduation::complete_graduation;
+pub use delist_token::delist_token;
+pub use redeem_delisted::redeem_delisted;
+pub use set_moderator::set_moderator;
+pub use place_hold::place_hold;
+pub use resolve_hold::resolve_hold;
@@ -0,0 +1,63 @@
+use cosmwasm_std::{DepsMut, Env, Event, MessageInfo, Order, Response};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    metadata::validate_uri,
+    state::{load_token_status, set_token_status, Dispute, TokenStatus, CONFIG, DISPUTES}
+};
+
+pub fn place_hold(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mint: String,
+    reason_uri: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    
+    // Only the moderator places holds
+    if config.moderator.as_ref() != Some(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    validate_uri("reason_uri", &reason_uri)?;
+    
+    // Trading and the reserve stay frozen until the hold is resolved
+    let previous_status = load_token_status(deps.storage, &denom)?;
+    let status_event = set_token_status(deps.storage, &denom, TokenStatus::OnHold)?;
+    if status_event.is_none() {
+        return Err(ContractError::InvalidTokenStatus { status: previous_status });
+    }
+    
+    let seq = DISPUTES
+        .prefix(denom.key())
+        .keys(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(0, |last| last + 1);
+    
+    let dispute = Dispute {
+        seq,
+        moderator: info.sender.clone(),
+        reason_uri: reason_uri.clone(),
+        placed_at: env.block.time.seconds(),
+        previous_status,
+        resolution: None,
+    };
+    DISPUTES.save(deps.storage, (denom.key(), seq), &dispute)?;
+    
+    let hold_event = Event::new("dispute_hold")
+        .add_attribute("mint", &mint)
+        .add_attribute("seq", seq.to_string())
+        .add_attribute("moderator", &info.sender)
+        .add_attribute("reason_uri", &reason_uri)
+        .add_attribute("previous_status", previous_status.as_str());
+    
+    Ok(Response::new()
+        .add_events(status_event)
+        .add_event(hold_event)
+        .add_attribute("action", "place_hold")
+        .add_attribute("mint", mint)
+        .add_attribute("seq", seq.to_string()))
+}
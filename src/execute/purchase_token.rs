@@ -1,19 +1,295 @@
 use cosmwasm_std::{
-    DepsMut, Env, MessageInfo, Response, Uint128, BankMsg, 
-    coins, WasmMsg, to_json_binary, StdError
+    Addr, Decimal256, DepsMut, Env, HexBinary, MessageInfo, Response, StdResult, Storage, Uint128
 };
 use crate::{
-    error::ContractError, 
-    state::{CONFIG, TOKEN_VAULTS, VIEWERSHIP_ORACLES},
-    utils::calculate_price
+    asset::PaymentAsset,
+    denom::FactoryDenom,
+    error::ContractError,
+    nft::royalty_recipient,
+    state::{
+        add_holdings, credit_claimable, current_price, load_oracle, load_token_metadata, load_vault,
+        set_token_status, Config, LaunchConfig, TokenMetadata, TokenStatus, TokenVault, CONFIG,
+        CREATOR_ROYALTIES, HOLDER_REWARDS_OUTSTANDING, LAUNCH_ALLOWLIST, LAUNCH_CONFIGS,
+        LAUNCH_PURCHASES, PRESALE_PURCHASES, REFERRAL_STATS, REVENUE_SPLITS, REWARD_POOLS,
+        STAKING_POOLS, STAKING_REWARDS_OUTSTANDING, TOKEN_ROYALTIES, TOKEN_SUPPLY, TOKEN_VAULTS
+    },
+    utils::{address_leaf, calculate_price, quote_at_price, split_amount, verify_merkle_proof, PurchaseQuote}
 };
 
-// Custom message structure to be passed to a Token Factory handler contract
-#[derive(serde::Serialize)]
-struct MintMsg {
-    denom: String,
-    amount: String,
-    recipient: String,
+// State a purchase reads, shared by native and CW20 payments
+pub(crate) struct PurchaseContext {
+    pub denom: FactoryDenom,
+    pub metadata: TokenMetadata,
+    pub vault: TokenVault,
+    pub config: Config,
+    pub supply: Uint128,
+    pub launch: Option<LaunchConfig>,
+    pub status: TokenStatus,
+    // Whether this purchase is made in the token's presale
+    pub presale: bool,
+    // Price per token in INJ for this buyer, after any presale discount
+    pub price_per_token: Uint128,
+    pub now: u64,
+}
+
+pub(crate) fn load_purchase_context(
+    storage: &dyn Storage,
+    env: &Env,
+    denom: FactoryDenom,
+    buyer: &Addr,
+    proof: Option<Vec<HexBinary>>,
+) -> Result<PurchaseContext, ContractError> {
+    let metadata = load_token_metadata(storage, &denom)?;
+    let oracle = load_oracle(storage, &denom)?;
+    let vault = load_vault(storage, &denom)?;
+    let config = CONFIG.load(storage)?;
+    let supply = TOKEN_SUPPLY.may_load(storage, denom.key())?.unwrap_or_default();
+    
+    let now = env.block.time.seconds();
+    let launch = LAUNCH_CONFIGS.may_load(storage, denom.key())?;
+    
+    let base_price = calculate_price(&oracle, supply)?;
+    let (status, sale, price_per_token) = current_price(storage, &denom, launch.as_ref(), base_price, now)?;
+    
+    // Presale purchases are limited to allowlisted buyers
+    if let Some(sale) = sale {
+        let proof = proof.unwrap_or_default();
+        if !verify_merkle_proof(&sale.merkle_root, address_leaf(buyer), &proof) {
+            return Err(ContractError::InvalidMerkleProof {});
+        }
+    }
+    let presale = sale.is_some();
+    
+    Ok(PurchaseContext {
+        denom,
+        metadata,
+        vault,
+        config,
+        supply,
+        launch,
+        status,
+        presale,
+        price_per_token,
+        now,
+    })
+}
+
+// Enforce the token's launch protections, recording amounts bought while they apply
+fn check_launch_rules(
+    storage: &mut dyn Storage,
+    ctx: &PurchaseContext,
+    buyer: &Addr,
+    amount_tokens: Uint128,
+) -> Result<(), ContractError> {
+    let (denom, now) = (&ctx.denom, ctx.now);
+    let launch = match &ctx.launch {
+        Some(launch) => launch,
+        None => return Ok(()),
+    };
+    
+    if let Some(max) = launch.max_per_tx {
+        if amount_tokens > max {
+            return Err(ContractError::ExceedsTransactionCap { max });
+        }
+    }
+    
+    // Presale buyers are held to the presale cap; public launch limits start at start_time
+    if ctx.presale {
+        if let Some(sale) = &launch.presale {
+            let key = (denom.key(), buyer);
+            let bought = PRESALE_PURCHASES.may_load(storage, key)?.unwrap_or_default() + amount_tokens;
+            if bought > sale.max_per_address {
+                return Err(ContractError::ExceedsPresaleCap { max: sale.max_per_address });
+            }
+            PRESALE_PURCHASES.save(storage, key, &bought)?;
+        }
+        return Ok(());
+    }
+    
+    if let Some(allowlist_end_time) = launch.allowlist_end_time {
+        if now < allowlist_end_time && !LAUNCH_ALLOWLIST.has(storage, (denom.key(), buyer)) {
+            return Err(ContractError::NotOnAllowlist {});
+        }
+    }
+    
+    if now < launch.protection_end_time {
+        if let Some(max) = launch.max_per_address {
+            let key = (denom.key(), buyer);
+            let bought = LAUNCH_PURCHASES.may_load(storage, key)?.unwrap_or_default() + amount_tokens;
+            if bought > max {
+                return Err(ContractError::ExceedsAddressCap { max });
+            }
+            LAUNCH_PURCHASES.save(storage, key, &bought)?;
+        }
+    }
+    
+    Ok(())
+}
+
+// Validate an optional referrer; buyers cannot refer themselves
+pub(crate) fn validate_referrer(
+    deps: &DepsMut,
+    buyer: &Addr,
+    referrer: Option<String>,
+) -> Result<Option<Addr>, ContractError> {
+    let referrer = referrer
+        .map(|referrer| deps.api.addr_validate(&referrer))
+        .transpose()?;
+    if referrer.as_ref() == Some(buyer) {
+        return Err(ContractError::SelfReferral {});
+    }
+    
+    Ok(referrer)
+}
+
+// Distribute a paid purchase: accrue fees and royalties, pay the vault and record supply.
+// `paid` is in the payment asset; `inj` is the same purchase priced in INJ and drives the
+// INJ-denominated totals (vault collected, royalty and referral stats).
+pub(crate) fn settle_purchase(
+    deps: DepsMut,
+    ctx: PurchaseContext,
+    buyer: &Addr,
+    referrer: Option<Addr>,
+    asset: &PaymentAsset,
+    paid: &PurchaseQuote,
+    inj: &PurchaseQuote,
+) -> Result<Response, ContractError> {
+    check_launch_rules(deps.storage, &ctx, buyer, paid.amount_tokens)?;
+    
+    let PurchaseContext { denom, metadata, mut vault, config, supply, status, presale, .. } = ctx;
+    let mint_key = denom.key();
+    let ledger_key = asset.ledger_key();
+    
+    // Referrers earn a share of the platform fee
+    let referral_reward = match &referrer {
+        Some(referrer) => {
+            let reward = paid.platform_fee.multiply_ratio(config.referral_share_bps, 10_000u128);
+            let reward_inj = inj.platform_fee.multiply_ratio(config.referral_share_bps, 10_000u128);
+            credit_claimable(deps.storage, referrer, &ledger_key, reward)?;
+            REFERRAL_STATS.update(deps.storage, referrer, |stats| -> StdResult<_> {
+                let mut stats = stats.unwrap_or_default();
+                stats.total_earned = stats.total_earned.checked_add(reward_inj)?;
+                stats.referred_purchases += 1;
+                Ok(stats)
+            })?;
+            reward
+        },
+        None => Uint128::zero(),
+    };
+    
+    // Holders share INJ platform fees, released to them by view updates. The fee is shared among
+    // current holders before the buyer's tokens are recorded, so it never pays the buyer
+    let holder_reward = match asset {
+        PaymentAsset::Native(denom) if denom == "inj" => {
+            paid.platform_fee.multiply_ratio(config.holder_reward_bps, 10_000u128)
+        },
+        _ => Uint128::zero(),
+    };
+    if !holder_reward.is_zero() {
+        let mut pool = REWARD_POOLS.may_load(deps.storage, mint_key)?.unwrap_or_default();
+        pool.pending += holder_reward;
+        pool.allocate();
+        REWARD_POOLS.save(deps.storage, mint_key, &pool)?;
+        let outstanding = HOLDER_REWARDS_OUTSTANDING.may_load(deps.storage)?.unwrap_or_default();
+        HOLDER_REWARDS_OUTSTANDING.save(deps.storage, &(outstanding + holder_reward))?;
+    }
+    
+    // Stakers share INJ platform fees as they are paid; with nothing staked the platform keeps it
+    let staking = STAKING_POOLS
+        .may_load(deps.storage, mint_key)?
+        .filter(|staking| !staking.total_staked.is_zero());
+    let staker_reward = match (asset, staking) {
+        (PaymentAsset::Native(denom), Some(mut staking)) if denom == "inj" => {
+            let reward = paid.platform_fee.multiply_ratio(config.staker_reward_bps, 10_000u128);
+            staking.reward_per_token += Decimal256::from_ratio(reward, staking.total_staked);
+            STAKING_POOLS.save(deps.storage, mint_key, &staking)?;
+            let outstanding = STAKING_REWARDS_OUTSTANDING.may_load(deps.storage)?.unwrap_or_default();
+            STAKING_REWARDS_OUTSTANDING.save(deps.storage, &(outstanding + reward))?;
+            reward
+        },
+        _ => Uint128::zero(),
+    };
+    
+    // Accrue the rest of the platform fee for the platform wallet to claim
+    let platform_share = paid.platform_fee - referral_reward - holder_reward - staker_reward;
+    credit_claimable(deps.storage, &config.platform_wallet, &ledger_key, platform_share)?;
+    
+    // Accrue artist royalty, divided between split recipients if the artist set a split. Each
+    // recipient's royalty total counts what they were actually paid, in INJ
+    if !paid.royalty.is_zero() {
+        let shares = match REVENUE_SPLITS.may_load(deps.storage, mint_key)? {
+            Some(split) => split_amount(paid.royalty, &split)
+                .into_iter()
+                .zip(split_amount(inj.royalty, &split))
+                .map(|((recipient, share), (_, share_inj))| (recipient, share, share_inj))
+                .collect(),
+            None => vec![(royalty_recipient(&deps.querier, &metadata), paid.royalty, inj.royalty)],
+        };
+        for (recipient, share, share_inj) in shares {
+            credit_claimable(deps.storage, &recipient, &ledger_key, share)?;
+            CREATOR_ROYALTIES.update(deps.storage, &recipient, |total| -> StdResult<_> {
+                Ok(total.unwrap_or_default().checked_add(share_inj)?)
+            })?;
+        }
+        
+        TOKEN_ROYALTIES.update(deps.storage, mint_key, |total| -> StdResult<_> {
+            Ok(total.unwrap_or_default().checked_add(inj.royalty)?)
+        })?;
+    }
+    
+    // Validate vault wallet address before sending
+    let vault_wallet_str = vault.inj_vault_wallet.to_string();
+    if let Err(e) = deps.api.addr_validate(&vault_wallet_str) {
+        return Err(ContractError::CustomError {
+            val: format!("Invalid vault wallet address: {}", e)
+        });
+    }
+    
+    // Send remainder to vault
+    let vault_msg = asset.transfer_msg(&vault.inj_vault_wallet, paid.vault_amount)?;
+    
+    // Update vault total collected, and the INJ part of it separately
+    let inj_paid = match asset {
+        PaymentAsset::Native(denom) if denom == "inj" => paid.vault_amount,
+        _ => Uint128::zero(),
+    };
+    vault.native_collected = Some(vault.inj_collected() + inj_paid);
+    vault.total_collected += inj.vault_amount;
+    TOKEN_VAULTS.save(deps.storage, mint_key, &vault)?;
+    
+    // The purchase that fills the vault to its liquidity threshold starts graduation
+    let status = if vault.total_collected >= vault.liquidity_threshold {
+        TokenStatus::Graduating
+    } else {
+        status
+    };
+    let status_event = set_token_status(deps.storage, &denom, status)?;
+    
+    // Track issued supply
+    let new_supply = supply.checked_add(paid.amount_tokens).map_err(|_| ContractError::SupplyOverflow {})?;
+    TOKEN_SUPPLY.save(deps.storage, mint_key, &new_supply)?;
+    
+    // Record the buyer's holding, settling rewards earned on what they already held
+    add_holdings(deps.storage, mint_key, buyer, paid.amount_tokens)?;
+    
+    // Create response with debugging information
+    Ok(Response::new()
+        .add_message(vault_msg)
+        .add_events(status_event)
+        .add_attribute("action", "purchase_token")
+        .add_attribute("mint_denom", denom.to_string())
+        .add_attribute("mint_amount", paid.amount_tokens.to_string())
+        .add_attribute("mint_to", buyer)
+        .add_attribute("payment_asset", ledger_key)
+        .add_attribute("total_cost", paid.total_cost.to_string())
+        .add_attribute("platform_fee", paid.platform_fee.to_string())
+        .add_attribute("referral_reward", referral_reward.to_string())
+        .add_attribute("holder_reward", holder_reward.to_string())
+        .add_attribute("staker_reward", staker_reward.to_string())
+        .add_attribute("royalty", paid.royalty.to_string())
+        .add_attribute("presale", presale.to_string())
+        .add_attribute("vault_addr", vault_wallet_str)
+        .add_attribute("status", status.as_str()))
 }
 
 pub fn purchase_token(
@@ -22,6 +298,8 @@ pub fn purchase_token(
     info: MessageInfo,
     mint: String,
     amount_tokens: Uint128,
+    referrer: Option<String>,
+    proof: Option<Vec<HexBinary>>,
 ) -> Result<Response, ContractError> {
     // Validate amount is not zero
     if amount_tokens.is_zero() {
@@ -29,33 +307,14 @@ pub fn purchase_token(
     }
     
     // Validate mint is a proper token factory denom
-    if !mint.starts_with("factory/") {
-        return Err(ContractError::InvalidDenom {});
-    }
-    
-    let mint_key = mint.as_bytes();
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let referrer = validate_referrer(&deps, &info.sender, referrer)?;
     
     // Load required data
-    let oracle = VIEWERSHIP_ORACLES.load(deps.storage, mint_key)?;
-    let mut vault = TOKEN_VAULTS.load(deps.storage, mint_key)?;
-    let config = CONFIG.load(deps.storage)?;
-    
-    // Verify vault is properly set up
-    if vault.vault_account.is_none() {
-        return Err(ContractError::CustomError { 
-            val: "Vault account not set up. Please call setup_vault_account first.".to_string() 
-        });
-    }
-    
-    // Calculate price based on viewership data
-    let price_per_token = calculate_price(&oracle, Uint128::zero())?;
-    
-    // Calculate total cost
-    let total_cost = price_per_token * amount_tokens;
+    let ctx = load_purchase_context(deps.storage, &env, denom, &info.sender, proof)?;
     
-    // Calculate platform fee (2.5%)
-    let platform_fee = total_cost.multiply_ratio(25u128, 1000u128);
-    let vault_amount = total_cost - platform_fee;
+    // Calculate total cost, platform fee and royalty from the buyer's price
+    let quote = quote_at_price(ctx.price_per_token, amount_tokens, ctx.metadata.royalty_bps)?;
     
     // Check if sufficient INJ was sent
     let payment = info
@@ -64,63 +323,21 @@ pub fn purchase_token(
         .find(|c| c.denom == "inj")
         .map(|c| c.amount)
         .unwrap_or(Uint128::zero());
-        
-    if payment < total_cost {
-        return Err(ContractError::InsufficientFunds {});
-    }
     
-    // Process payments
-    let mut messages = vec![];
-    
-    // Validate platform wallet address before sending
-    let platform_wallet_str = config.platform_wallet.to_string();
-    if let Err(e) = deps.api.addr_validate(&platform_wallet_str) {
-        return Err(ContractError::CustomError { 
-            val: format!("Invalid platform wallet address: {}", e) 
-        });
-    }
-    
-    // Send platform fee
-    let platform_msg = BankMsg::Send {
-        to_address: platform_wallet_str.clone(),
-        amount: coins(platform_fee.u128(), "inj"),
-    };
-    messages.push(cosmwasm_std::CosmosMsg::Bank(platform_msg));
-    
-    // Validate vault wallet address before sending
-    let vault_wallet_str = vault.inj_vault_wallet.to_string();
-    if let Err(e) = deps.api.addr_validate(&vault_wallet_str) {
-        return Err(ContractError::CustomError { 
-            val: format!("Invalid vault wallet address: {}", e) 
-        });
+    if payment < quote.total_cost {
+        return Err(ContractError::InsufficientFunds {});
     }
     
-    // Send remainder to vault - use clone() to avoid moving the string
-    let vault_msg = BankMsg::Send {
-        to_address: vault_wallet_str.clone(),
-        amount: coins(vault_amount.u128(), "inj"),
-    };
-    messages.push(cosmwasm_std::CosmosMsg::Bank(vault_msg));
-    
-    // Update vault total collected
-    vault.total_collected += vault_amount;
-    TOKEN_VAULTS.save(deps.storage, mint_key, &vault)?;
+    let asset = PaymentAsset::Native("inj".to_string());
+    let mut response = settle_purchase(deps, ctx, &info.sender, referrer, &asset, &quote, &quote)?;
     
-    // Create response with debugging information
-    let mut response = Response::new()
-        .add_messages(messages)
-        .add_attribute("action", "purchase_token")
-        .add_attribute("mint_denom", mint)
-        .add_attribute("mint_amount", amount_tokens.to_string())
-        .add_attribute("mint_to", info.sender)
-        .add_attribute("total_cost", total_cost.to_string())
-        .add_attribute("vault_addr", vault_wallet_str); // Now we can use it again
-        
-    if vault.total_collected >= vault.liquidity_threshold {
-        if let Some(dex_pool) = &vault.dex_pool {
-            response = response.add_attribute("notice", "Liquidity threshold reached - consider providing liquidity");
-        }
+    // Return any INJ sent beyond the cost
+    let refund = payment - quote.total_cost;
+    if !refund.is_zero() {
+        response = response
+            .add_message(asset.transfer_msg(&info.sender, refund)?)
+            .add_attribute("refund", refund.to_string());
     }
     
     Ok(response)
-}
\ No newline at end of file
+}
@@ -1,24 +1,67 @@
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{from_json, DepsMut, Env, MessageInfo, Response, Uint128};
 use cw20::Cw20ReceiveMsg;
 use crate::{
+    asset::PaymentAsset,
+    denom::FactoryDenom,
     error::ContractError,
+    msg::ReceiveMsg,
+    state::QUOTE_TOKENS,
+    utils::{convert_inj_amount, quote_at_price}
 };
+use super::purchase_token::{load_purchase_context, settle_purchase, validate_referrer};
 
 // Handler for CW20 token receives
 pub fn receive_cw20(
-    _deps: DepsMut,
-    _env: Env,
+    deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     cw20_msg: Cw20ReceiveMsg,
 ) -> Result<Response, ContractError> {
-    // This function would handle receiving CW20 tokens
-    // For this example, we'll just return a basic success response
-    // In a real implementation, you might want to parse the cw20_msg.msg
-    // to determine what action to take with the received tokens
+    // info.sender is the CW20 contract, which must be an accepted quote token
+    let inj_rate = QUOTE_TOKENS
+        .may_load(deps.storage, &info.sender)?
+        .ok_or(ContractError::UnsupportedQuoteToken {})?;
+    let buyer = deps.api.addr_validate(&cw20_msg.sender)?;
     
-    Ok(Response::new()
-        .add_attribute("action", "receive_cw20")
-        .add_attribute("sender", cw20_msg.sender)
-        .add_attribute("amount", cw20_msg.amount.to_string())
-        .add_attribute("from_contract", info.sender.to_string()))
+    // Reject payloads we do not understand so the transfer reverts
+    let receive_msg: ReceiveMsg = from_json(&cw20_msg.msg)
+        .map_err(|_| ContractError::InvalidReceiveMsg {})?;
+    
+    match receive_msg {
+        ReceiveMsg::Purchase { mint, min_tokens_out, referrer, proof } => {
+            let denom = FactoryDenom::parse(deps.api, &mint)?;
+            let referrer = validate_referrer(&deps, &buyer, referrer)?;
+            let ctx = load_purchase_context(deps.storage, &env, denom, &buyer, proof)?;
+            
+            // Price the token in the quote token, then buy as many whole tokens as the amount covers
+            let price_inj = ctx.price_per_token;
+            let price = convert_inj_amount(price_inj, inj_rate)?;
+            if price.is_zero() {
+                return Err(ContractError::UnsupportedQuoteToken {});
+            }
+            let amount_tokens = cw20_msg.amount / price;
+            if amount_tokens.is_zero() || amount_tokens < min_tokens_out {
+                return Err(ContractError::SlippageExceeded {
+                    min_tokens_out,
+                    amount_tokens,
+                });
+            }
+            
+            let paid = quote_at_price(price, amount_tokens, ctx.metadata.royalty_bps)?;
+            let inj = quote_at_price(price_inj, amount_tokens, ctx.metadata.royalty_bps)?;
+            
+            let asset = PaymentAsset::Cw20(info.sender.clone());
+            let mut response = settle_purchase(deps, ctx, &buyer, referrer, &asset, &paid, &inj)?;
+            
+            // Return whatever did not buy a whole token
+            let refund = cw20_msg.amount - paid.total_cost;
+            if refund > Uint128::zero() {
+                response = response
+                    .add_message(asset.transfer_msg(&buyer, refund)?)
+                    .add_attribute("refund", refund.to_string());
+            }
+            
+            Ok(response)
+        },
+    }
 }
@@ -0,0 +1,50 @@
+use cosmwasm_std::{coins, BankMsg, DepsMut, Env, MessageInfo, Response};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    nft::assert_rights_holder,
+    state::{add_holdings, assert_not_on_hold, load_token_metadata, AIRDROPS}
+};
+
+pub fn reclaim_airdrop(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mint: String,
+) -> Result<Response, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    assert_not_on_hold(deps.storage, &denom)?;
+    let metadata = load_token_metadata(deps.storage, &denom)?;
+    
+    // Unclaimed tokens go back to the token's rights holder
+    assert_rights_holder(&deps.querier, &metadata, &info.sender)?;
+    
+    let mut airdrop = AIRDROPS
+        .may_load(deps.storage, denom.key())?
+        .ok_or_else(|| ContractError::AirdropNotFound { mint: mint.clone() })?;
+    
+    if env.block.time.seconds() < airdrop.expiry {
+        return Err(ContractError::AirdropNotExpired { expiry: airdrop.expiry });
+    }
+    
+    let amount = airdrop.total_amount - airdrop.claimed_amount;
+    if airdrop.reclaimed || amount.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+    
+    airdrop.reclaimed = true;
+    AIRDROPS.save(deps.storage, denom.key(), &airdrop)?;
+    add_holdings(deps.storage, denom.key(), &info.sender, amount)?;
+    
+    let send_msg = BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: coins(amount.u128(), denom.as_str()),
+    };
+    
+    Ok(Response::new()
+        .add_message(send_msg)
+        .add_attribute("action", "reclaim_airdrop")
+        .add_attribute("mint", mint)
+        .add_attribute("recipient", info.sender)
+        .add_attribute("amount", amount.to_string()))
+}
@@ -0,0 +1,44 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+use crate::{
+    asset::PaymentAsset,
+    error::ContractError,
+    state::{reserved_amount, CONFIG}
+};
+
+pub fn recover_tokens(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    asset: String,
+    amount: Uint128,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    
+    // Only the authority can recover funds
+    if info.sender != config.authority {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    if amount.is_zero() {
+        return Err(ContractError::InvalidAmount {});
+    }
+    
+    let payment_asset = PaymentAsset::from_ledger_key(deps.api, &asset)?;
+    let recipient = deps.api.addr_validate(&recipient)?;
+    
+    // Anything above what the contract owes to claimants and token reserves is recoverable
+    let balance = payment_asset.query_balance(&deps.querier, &env.contract.address)?;
+    let reserved = reserved_amount(deps.storage, &payment_asset.ledger_key())?;
+    let available = balance.saturating_sub(reserved);
+    if amount > available {
+        return Err(ContractError::ExceedsRecoverable { available });
+    }
+    
+    Ok(Response::new()
+        .add_message(payment_asset.transfer_msg(&recipient, amount)?)
+        .add_attribute("action", "recover_tokens")
+        .add_attribute("asset", asset)
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("recipient", recipient))
+}
@@ -0,0 +1,72 @@
+use cosmwasm_std::{coins, BankMsg, DepsMut, Env, MessageInfo, Response};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    state::{load_token_status, remove_holdings, TokenStatus, DELISTINGS, REDEMPTION_RESERVES_OUTSTANDING, TOKEN_SUPPLY},
+    utils::accrued_reward
+};
+
+pub fn redeem_delisted(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    mint: String,
+) -> Result<Response, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    
+    // Only delisted tokens redeem
+    let status = load_token_status(deps.storage, &denom)?;
+    if status != TokenStatus::Delisted {
+        return Err(ContractError::InvalidTokenStatus { status });
+    }
+    let mut delisting = DELISTINGS.load(deps.storage, denom.key())?;
+    
+    // Holders send the tokens they burn
+    let amount = match info.funds.as_slice() {
+        [coin] if coin.denom == mint && !coin.amount.is_zero() => coin.amount,
+        _ => return Err(ContractError::InvalidRedeemFunds { denom: mint }),
+    };
+    
+    // Redemptions are capped at the supply the rate was fixed against
+    let redeemable = delisting.supply - delisting.redeemed;
+    if amount > redeemable {
+        return Err(ContractError::ExceedsRedeemableSupply { remaining: redeemable });
+    }
+    
+    // Pay at the rate fixed at delisting, never past what is left of the reserve;
+    // the redemption that completes the supply also takes the rounding remainder
+    let remaining = delisting.reserve - delisting.paid_out;
+    delisting.redeemed += amount;
+    let payout = if delisting.redeemed >= delisting.supply {
+        remaining
+    } else {
+        accrued_reward(amount, delisting.rate)?.min(remaining)
+    };
+    delisting.paid_out += payout;
+    DELISTINGS.save(deps.storage, denom.key(), &delisting)?;
+    remove_holdings(deps.storage, denom.key(), &info.sender, amount)?;
+    
+    let outstanding = REDEMPTION_RESERVES_OUTSTANDING.may_load(deps.storage)?.unwrap_or_default();
+    REDEMPTION_RESERVES_OUTSTANDING.save(deps.storage, &outstanding.saturating_sub(payout))?;
+    
+    let supply = TOKEN_SUPPLY.may_load(deps.storage, denom.key())?.unwrap_or_default();
+    TOKEN_SUPPLY.save(deps.storage, denom.key(), &supply.saturating_sub(amount))?;
+    
+    let mut response = Response::new();
+    if !payout.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: coins(payout.u128(), "inj"),
+        });
+    }
+    
+    // In a real implementation, the tokens would be burned through Injective's token factory
+    // For now, we'll just record the intent in the response; the tokens stay reserved so
+    // RecoverTokens cannot return them to circulation
+    Ok(response
+        .add_attribute("action", "redeem_delisted")
+        .add_attribute("mint", mint)
+        .add_attribute("redeemer", info.sender)
+        .add_attribute("burn_amount", amount.to_string())
+        .add_attribute("payout", payout.to_string()))
+}
@@ -0,0 +1,61 @@
+use cosmwasm_std::{DepsMut, Env, HexBinary, MessageInfo, Response, Uint128};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    nft::assert_rights_holder,
+    state::{load_token_metadata, Airdrop, AIRDROPS}
+};
+
+pub fn register_airdrop(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mint: String,
+    merkle_root: HexBinary,
+    total_amount: Uint128,
+    expiry: u64,
+) -> Result<Response, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let metadata = load_token_metadata(deps.storage, &denom)?;
+    
+    // Only the token's rights holder airdrops its tokens
+    assert_rights_holder(&deps.querier, &metadata, &info.sender)?;
+    
+    if merkle_root.len() != 32 || total_amount.is_zero() || expiry <= env.block.time.seconds() {
+        return Err(ContractError::InvalidAirdrop {});
+    }
+    
+    if AIRDROPS.has(deps.storage, denom.key()) {
+        return Err(ContractError::AirdropAlreadyExists {});
+    }
+    
+    // The allocation must be deposited with this message
+    let deposited = info
+        .funds
+        .iter()
+        .find(|c| c.denom == mint)
+        .map(|c| c.amount)
+        .unwrap_or_default();
+    if deposited != total_amount {
+        return Err(ContractError::InvalidDeposit {
+            expected: total_amount,
+            denom: mint,
+        });
+    }
+    
+    let airdrop = Airdrop {
+        merkle_root,
+        total_amount,
+        claimed_amount: Uint128::zero(),
+        expiry,
+        reclaimed: false,
+    };
+    AIRDROPS.save(deps.storage, denom.key(), &airdrop)?;
+    
+    Ok(Response::new()
+        .add_attribute("action", "register_airdrop")
+        .add_attribute("mint", mint)
+        .add_attribute("merkle_root", airdrop.merkle_root.to_hex())
+        .add_attribute("total_amount", total_amount.to_string())
+        .add_attribute("expiry", expiry.to_string()))
+}
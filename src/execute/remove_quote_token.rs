@@ -0,0 +1,31 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use crate::{
+    error::ContractError,
+    state::{CONFIG, QUOTE_TOKENS}
+};
+
+pub fn remove_quote_token(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    
+    // Only the authority manages accepted quote tokens
+    if info.sender != config.authority {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    let token_addr = deps.api.addr_validate(&token)?;
+    if !QUOTE_TOKENS.has(deps.storage, &token_addr) {
+        return Err(ContractError::UnsupportedQuoteToken {});
+    }
+    
+    // Balances already accrued in this token stay claimable
+    QUOTE_TOKENS.remove(deps.storage, &token_addr);
+    
+    Ok(Response::new()
+        .add_attribute("action", "remove_quote_token")
+        .add_attribute("token", token_addr))
+}
@@ -0,0 +1,94 @@
+use cosmwasm_std::{DepsMut, Env, Event, MessageInfo, Response};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    metadata::validate_uri,
+    state::{
+        load_token_status, open_dispute, release_hold, resolve_open_dispute, Dispute, DisputeResolution,
+        HoldOutcome, CONFIG, LAUNCH_CONFIGS
+    }
+};
+use super::delist_token::delist;
+
+pub(crate) fn resolved_event(mint: &str, dispute: &Dispute) -> Event {
+    let mut event = Event::new("dispute_resolved")
+        .add_attribute("mint", mint)
+        .add_attribute("seq", dispute.seq.to_string());
+    if let Some(resolution) = &dispute.resolution {
+        event = event
+            .add_attribute("outcome", resolution.outcome.as_str())
+            .add_attribute("resolved_by", &resolution.resolved_by)
+            .add_attribute("resolution_uri", resolution.resolution_uri.clone().unwrap_or_default());
+    }
+    
+    event
+}
+
+pub fn resolve_hold(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mint: String,
+    outcome: HoldOutcome,
+    resolution_uri: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    
+    // Only the moderator resolves holds
+    if config.moderator.as_ref() != Some(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    if let Some(uri) = &resolution_uri {
+        validate_uri("resolution_uri", uri)?;
+    }
+    
+    let dispute = match open_dispute(deps.storage, denom.key())? {
+        Some(dispute) => dispute,
+        None => {
+            let status = load_token_status(deps.storage, &denom)?;
+            return Err(ContractError::InvalidTokenStatus { status });
+        },
+    };
+    
+    let now = env.block.time.seconds();
+    let mut response = Response::new();
+    match outcome {
+        // The token returns to where it was, less any presale that ended during the hold
+        HoldOutcome::Release => {
+            let launch = LAUNCH_CONFIGS.may_load(deps.storage, denom.key())?;
+            let status = dispute.previous_status.at(launch.as_ref(), now);
+            response = response.add_event(release_hold(deps.storage, &denom, status)?);
+        },
+        // The vault's collected INJ is returned with this message to back redemptions
+        HoldOutcome::Delist => {
+            let deposit = info
+                .funds
+                .iter()
+                .find(|c| c.denom == "inj")
+                .map(|c| c.amount)
+                .unwrap_or_default();
+            let (delisting, status_event) =
+                delist(deps.storage, &denom, deposit, Some(dispute.reason_uri.clone()), now)?;
+            response = response
+                .add_events(status_event)
+                .add_attribute("reserve", delisting.reserve.to_string())
+                .add_attribute("rate", delisting.rate.to_string());
+        },
+    }
+    
+    let resolution = DisputeResolution {
+        outcome,
+        resolved_by: info.sender,
+        resolved_at: now,
+        resolution_uri,
+    };
+    let dispute = resolve_open_dispute(deps.storage, denom.key(), resolution)?.unwrap_or(dispute);
+    
+    Ok(response
+        .add_event(resolved_event(&mint, &dispute))
+        .add_attribute("action", "resolve_hold")
+        .add_attribute("mint", mint)
+        .add_attribute("seq", dispute.seq.to_string()))
+}
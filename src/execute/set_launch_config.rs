@@ -0,0 +1,111 @@
+use cosmwasm_std::{DepsMut, Env, Event, MessageInfo, Response, Storage};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::LaunchParams,
+    nft::assert_rights_holder,
+    state::{
+        load_token_metadata, load_token_status, set_token_status, LaunchConfig, PresaleConfig, TokenStatus,
+        LAUNCH_CONFIGS, TOKEN_SUPPLY
+    }
+};
+
+// Validate launch settings and resolve their durations into times
+pub(crate) fn build_launch_config(params: LaunchParams) -> Result<LaunchConfig, ContractError> {
+    let LaunchParams {
+        start_time,
+        allowlist_duration,
+        protection_duration,
+        max_per_address,
+        max_per_tx,
+        presale,
+    } = params;
+    
+    // Caps must allow at least one token
+    if max_per_address.is_some_and(|max| max.is_zero()) || max_per_tx.is_some_and(|max| max.is_zero()) {
+        return Err(ContractError::InvalidLaunchConfig {});
+    }
+    
+    // The presale needs a 32-byte root, a window that ends at start_time and a sane discount
+    let presale = match presale {
+        Some(params) => {
+            if params.merkle_root.len() != 32
+                || params.duration == 0
+                || params.duration > start_time
+                || params.discount_bps > 10_000
+                || params.max_per_address.is_zero()
+            {
+                return Err(ContractError::InvalidLaunchConfig {});
+            }
+            Some(PresaleConfig {
+                merkle_root: params.merkle_root,
+                start_time: start_time - params.duration,
+                discount_bps: params.discount_bps,
+                max_per_address: params.max_per_address,
+            })
+        },
+        None => None,
+    };
+    
+    Ok(LaunchConfig {
+        start_time,
+        allowlist_end_time: allowlist_duration.map(|duration| start_time + duration),
+        protection_end_time: start_time + protection_duration,
+        max_per_address,
+        max_per_tx,
+        presale,
+    })
+}
+
+// Store a launch config; a token already open for trading moves in or out of its presale
+pub(crate) fn save_launch_config(
+    storage: &mut dyn Storage,
+    denom: &FactoryDenom,
+    config: &LaunchConfig,
+    now: u64,
+) -> Result<Option<Event>, ContractError> {
+    LAUNCH_CONFIGS.save(storage, denom.key(), config)?;
+    
+    match load_token_status(storage, denom)? {
+        TokenStatus::Presale | TokenStatus::Live => {
+            set_token_status(storage, denom, TokenStatus::opening(Some(config), now))
+        },
+        _ => Ok(None),
+    }
+}
+
+pub fn set_launch_config(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mint: String,
+    params: LaunchParams,
+) -> Result<Response, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let metadata = load_token_metadata(deps.storage, &denom)?;
+    
+    // Only the token's rights holder configures its launch
+    assert_rights_holder(&deps.querier, &metadata, &info.sender)?;
+    
+    // Launch settings only apply before trading has moved on
+    let status = load_token_status(deps.storage, &denom)?;
+    if !matches!(status, TokenStatus::Draft | TokenStatus::Presale | TokenStatus::Live) {
+        return Err(ContractError::InvalidTokenStatus { status });
+    }
+    
+    // Launch settings are fixed once the token has been bought
+    let supply = TOKEN_SUPPLY.may_load(deps.storage, denom.key())?.unwrap_or_default();
+    if !supply.is_zero() {
+        return Err(ContractError::LaunchLocked {});
+    }
+    
+    let config = build_launch_config(params)?;
+    let status_event = save_launch_config(deps.storage, &denom, &config, env.block.time.seconds())?;
+    
+    Ok(Response::new()
+        .add_events(status_event)
+        .add_attribute("action", "set_launch_config")
+        .add_attribute("mint", mint)
+        .add_attribute("start_time", config.start_time.to_string())
+        .add_attribute("protection_end_time", config.protection_end_time.to_string()))
+}
@@ -0,0 +1,32 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use crate::{
+    error::ContractError,
+    state::CONFIG
+};
+
+pub fn set_master_nft_contract(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    contract: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    
+    // Only the authority picks the collection
+    if info.sender != config.authority {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    // Tokens keep the collection their master NFT was minted in
+    config.master_nft_contract = contract
+        .map(|contract| deps.api.addr_validate(&contract))
+        .transpose()?;
+    CONFIG.save(deps.storage, &config)?;
+    
+    Ok(Response::new()
+        .add_attribute("action", "set_master_nft_contract")
+        .add_attribute(
+            "contract",
+            config.master_nft_contract.map(|contract| contract.to_string()).unwrap_or_default(),
+        ))
+}
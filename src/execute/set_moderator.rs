@@ -0,0 +1,32 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use crate::{
+    error::ContractError,
+    state::CONFIG
+};
+
+pub fn set_moderator(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    moderator: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    
+    // Only the authority appoints the moderator
+    if info.sender != config.authority {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    // Open holds stay in place; the new moderator resolves them
+    config.moderator = moderator
+        .map(|moderator| deps.api.addr_validate(&moderator))
+        .transpose()?;
+    CONFIG.save(deps.storage, &config)?;
+    
+    Ok(Response::new()
+        .add_attribute("action", "set_moderator")
+        .add_attribute(
+            "moderator",
+            config.moderator.map(|moderator| moderator.to_string()).unwrap_or_default(),
+        ))
+}
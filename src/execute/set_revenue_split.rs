@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::SplitRecipientMsg,
+    nft::assert_rights_holder,
+    state::{load_token_metadata, SplitRecipient, REVENUE_SPLITS, TOKEN_SUPPLY},
+    utils::MAX_SPLIT_RECIPIENTS
+};
+
+pub fn set_revenue_split(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    mint: String,
+    recipients: Vec<SplitRecipientMsg>,
+) -> Result<Response, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let metadata = load_token_metadata(deps.storage, &denom)?;
+    
+    // Only the token's rights holder can split its royalty
+    assert_rights_holder(&deps.querier, &metadata, &info.sender)?;
+    
+    // The split is fixed once the token has been bought
+    let supply = TOKEN_SUPPLY.may_load(deps.storage, denom.key())?.unwrap_or_default();
+    if !supply.is_zero() {
+        return Err(ContractError::SplitLocked {});
+    }
+    
+    if recipients.is_empty() || recipients.len() > MAX_SPLIT_RECIPIENTS {
+        return Err(ContractError::InvalidSplit {});
+    }
+    
+    // Validate recipients: unique addresses with positive weights summing to 100%
+    let mut seen = HashSet::new();
+    let mut total_weight: u32 = 0;
+    let mut split = Vec::with_capacity(recipients.len());
+    for recipient in recipients {
+        let address = deps.api.addr_validate(&recipient.address)?;
+        if recipient.weight_bps == 0 || !seen.insert(address.clone()) {
+            return Err(ContractError::InvalidSplit {});
+        }
+        total_weight += u32::from(recipient.weight_bps);
+        split.push(SplitRecipient {
+            address,
+            weight_bps: recipient.weight_bps,
+        });
+    }
+    
+    if total_weight != 10_000 {
+        return Err(ContractError::InvalidSplit {});
+    }
+    
+    REVENUE_SPLITS.save(deps.storage, denom.key(), &split)?;
+    
+    Ok(Response::new()
+        .add_attribute("action", "set_revenue_split")
+        .add_attribute("mint", mint)
+        .add_attribute("recipients", split.len().to_string()))
+}
@@ -1,37 +1,38 @@
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Addr};
 use crate::{
+    denom::FactoryDenom,
     error::ContractError,
-    state::{TOKEN_VAULTS}
+    state::{load_token_status, load_vault, set_token_status, TokenStatus, LAUNCH_CONFIGS, TOKEN_VAULTS}
 };
 
 pub fn setup_vault_account(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     mint: String,
 ) -> Result<Response, ContractError> {
     // Validate mint is a proper token factory denom
-    if !mint.starts_with("factory/") {
-        return Err(ContractError::InvalidDenom {});
-    }
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let mint_key = denom.key();
     
-    let mint_key = mint.as_bytes();
+    // Only the denom's creator opens it for trading, and never while it is held or delisted
+    if info.sender != denom.creator {
+        return Err(ContractError::Unauthorized {});
+    }
+    let status = load_token_status(deps.storage, &denom)?;
+    if matches!(status, TokenStatus::OnHold | TokenStatus::Delisted) {
+        return Err(ContractError::InvalidTokenStatus { status });
+    }
     
-    let mut vault = TOKEN_VAULTS.load(deps.storage, mint_key)?;
+    let mut vault = load_vault(deps.storage, &denom)?;
     
     // Check if vault is already fully set up
     if vault.vault_account.is_some() {
         return Err(ContractError::VaultAlreadySetup {});
     }
     
-    // Extract creator address from the denom (assuming format is factory/{creator}/{subdenom})
-    let parts: Vec<&str> = mint.split('/').collect();
-    if parts.len() < 3 {
-        return Err(ContractError::InvalidDenom {});
-    }
-    
-    let creator = parts[1];
-    let subdenom = parts[2];
+    let creator = denom.creator.as_str();
+    let subdenom = denom.subdenom.as_str();
     
     // Create deterministic vault addresses using proper bech32 format
     // Use prefix "inj1v" for vault and "inj1i" for inj vault to ensure proper bech32 format
@@ -51,9 +52,16 @@ pub fn setup_vault_account(
     
     TOKEN_VAULTS.save(deps.storage, mint_key, &vault)?;
     
+    // A set up token opens for trading
+    let launch = LAUNCH_CONFIGS.may_load(deps.storage, mint_key)?;
+    let status = TokenStatus::opening(launch.as_ref(), env.block.time.seconds());
+    let status_event = set_token_status(deps.storage, &denom, status)?;
+    
     Ok(Response::new()
+        .add_events(status_event)
         .add_attribute("action", "setup_vault_account")
         .add_attribute("mint", mint)
         .add_attribute("vault_account", vault_addr.to_string())
-        .add_attribute("inj_vault_wallet", inj_vault_wallet.to_string()))
+        .add_attribute("inj_vault_wallet", inj_vault_wallet.to_string())
+        .add_attribute("status", status.as_str()))
 }
\ No newline at end of file
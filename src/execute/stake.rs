@@ -0,0 +1,41 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    state::{assert_not_on_hold, load_staker, load_token_metadata, remove_holdings, STAKERS, STAKING_POOLS}
+};
+
+pub fn stake(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    mint: String,
+) -> Result<Response, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    load_token_metadata(deps.storage, &denom)?;
+    assert_not_on_hold(deps.storage, &denom)?;
+    
+    // Only the song's own tokens can be staked into its pool
+    let amount = match info.funds.as_slice() {
+        [coin] if coin.denom == mint && !coin.amount.is_zero() => coin.amount,
+        _ => return Err(ContractError::InvalidStakeFunds { denom: mint }),
+    };
+    
+    let mut pool = STAKING_POOLS.may_load(deps.storage, denom.key())?.unwrap_or_default();
+    let mut staker = load_staker(deps.storage, denom.key(), &info.sender, &pool)?;
+    staker.staked += amount;
+    pool.total_staked += amount;
+    
+    STAKERS.save(deps.storage, (denom.key(), &info.sender), &staker)?;
+    STAKING_POOLS.save(deps.storage, denom.key(), &pool)?;
+    
+    // Staked tokens earn staking rewards instead of holder rewards
+    remove_holdings(deps.storage, denom.key(), &info.sender, amount)?;
+    
+    Ok(Response::new()
+        .add_attribute("action", "stake")
+        .add_attribute("mint", mint)
+        .add_attribute("staker", info.sender)
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("staked", staker.staked.to_string()))
+}
@@ -0,0 +1,49 @@
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    state::{assert_not_on_hold, load_staker, Unbonding, CONFIG, STAKERS, STAKING_POOLS, UNBONDING}
+};
+
+pub fn unstake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mint: String,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidAmount {});
+    }
+    
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    assert_not_on_hold(deps.storage, &denom)?;
+    let config = CONFIG.load(deps.storage)?;
+    
+    let mut pool = STAKING_POOLS.may_load(deps.storage, denom.key())?.unwrap_or_default();
+    let mut staker = load_staker(deps.storage, denom.key(), &info.sender, &pool)?;
+    if amount > staker.staked {
+        return Err(ContractError::InsufficientStake { staked: staker.staked });
+    }
+    
+    // Unstaked tokens stop earning right away and are withdrawable after the unbonding period
+    staker.staked -= amount;
+    pool.total_staked -= amount;
+    pool.total_unbonding += amount;
+    
+    let release_time = env.block.time.seconds() + config.unbonding_period;
+    let key = (denom.key(), &info.sender);
+    let mut queue = UNBONDING.may_load(deps.storage, key)?.unwrap_or_default();
+    queue.push(Unbonding { amount, release_time });
+    
+    STAKERS.save(deps.storage, key, &staker)?;
+    STAKING_POOLS.save(deps.storage, denom.key(), &pool)?;
+    UNBONDING.save(deps.storage, key, &queue)?;
+    
+    Ok(Response::new()
+        .add_attribute("action", "unstake")
+        .add_attribute("mint", mint)
+        .add_attribute("staker", info.sender)
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("release_time", release_time.to_string()))
+}
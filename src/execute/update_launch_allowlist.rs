@@ -0,0 +1,38 @@
+use cosmwasm_std::{DepsMut, Empty, Env, MessageInfo, Response};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    nft::assert_rights_holder,
+    state::{load_token_metadata, LAUNCH_ALLOWLIST}
+};
+
+pub fn update_launch_allowlist(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    mint: String,
+    add: Vec<String>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let metadata = load_token_metadata(deps.storage, &denom)?;
+    
+    // Only the token's rights holder manages its allowlist
+    assert_rights_holder(&deps.querier, &metadata, &info.sender)?;
+    
+    for address in &add {
+        let address = deps.api.addr_validate(address)?;
+        LAUNCH_ALLOWLIST.save(deps.storage, (denom.key(), &address), &Empty {})?;
+    }
+    
+    for address in &remove {
+        let address = deps.api.addr_validate(address)?;
+        LAUNCH_ALLOWLIST.remove(deps.storage, (denom.key(), &address));
+    }
+    
+    Ok(Response::new()
+        .add_attribute("action", "update_launch_allowlist")
+        .add_attribute("mint", mint)
+        .add_attribute("added", add.len().to_string())
+        .add_attribute("removed", remove.len().to_string()))
+}
@@ -1,7 +1,8 @@
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
 use crate::{
+    denom::FactoryDenom,
     error::ContractError,
-    state::{CONFIG, VIEWERSHIP_ORACLES}
+    state::{load_oracle, load_token_status, TokenStatus, CONFIG, REWARD_POOLS, VIEWERSHIP_ORACLES}
 };
 
 pub fn update_oracle(
@@ -20,19 +21,32 @@ pub fn update_oracle(
     }
     
     // Validate mint is a proper token factory denom
-    if !mint.starts_with("factory/") {
-        return Err(ContractError::InvalidDenom {});
-    }
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let key = denom.key();
     
-    let key = mint.as_bytes();
+    let mut oracle = load_oracle(deps.storage, &denom)?;
     
-    let mut oracle = VIEWERSHIP_ORACLES.load(deps.storage, key)?;
+    // Delisted songs no longer take view updates, and held ones wait for their release; counts
+    // are cumulative, so views reported after a release are not lost
+    let status = load_token_status(deps.storage, &denom)?;
+    if matches!(status, TokenStatus::Delisted | TokenStatus::OnHold) {
+        return Err(ContractError::InvalidTokenStatus { status });
+    }
     
     // Validate the new view count (should be increasing)
     if new_view_count < oracle.view_count {
         return Err(ContractError::InvalidViewCount {});
     }
     
+    // New views release the song's pending holder rewards
+    let mut released = Uint128::zero();
+    if new_view_count > oracle.view_count {
+        if let Some(mut pool) = REWARD_POOLS.may_load(deps.storage, key)? {
+            released = pool.release();
+            REWARD_POOLS.save(deps.storage, key, &pool)?;
+        }
+    }
+    
     // Update the oracle data
     oracle.view_count = new_view_count;
     oracle.last_updated = _env.block.time.seconds();
@@ -42,5 +56,6 @@ pub fn update_oracle(
     Ok(Response::new()
         .add_attribute("action", "update_oracle")
         .add_attribute("mint", mint)
-        .add_attribute("new_view_count", new_view_count.to_string()))
+        .add_attribute("new_view_count", new_view_count.to_string())
+        .add_attribute("rewards_released", released.to_string()))
 }
\ No newline at end of file
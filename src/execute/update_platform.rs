@@ -1,15 +1,21 @@
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
 use crate::{
     error::ContractError,
     state::{CONFIG}
 };
 
+#[allow(clippy::too_many_arguments)]
 pub fn update_platform(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
     new_platform_wallet: String,
     new_oracle_authority: Option<String>,
+    new_referral_share_bps: Option<u16>,
+    new_holder_reward_bps: Option<u16>,
+    new_staker_reward_bps: Option<u16>,
+    new_unbonding_period: Option<u64>,
+    new_launch_fee: Option<Uint128>,
 ) -> Result<Response, ContractError> {
     let mut config = CONFIG.load(deps.storage)?;
     
@@ -26,6 +32,42 @@ pub fn update_platform(
         config.oracle_authority = deps.api.addr_validate(&new_auth)?;
     }
     
+    // Update referral share if provided
+    if let Some(share_bps) = new_referral_share_bps {
+        if share_bps > 10_000 {
+            return Err(ContractError::InvalidReferralShare {});
+        }
+        config.referral_share_bps = share_bps;
+    }
+    
+    // Update holder reward share if provided
+    if let Some(share_bps) = new_holder_reward_bps {
+        config.holder_reward_bps = share_bps;
+    }
+    
+    // Update staker reward share if provided
+    if let Some(share_bps) = new_staker_reward_bps {
+        config.staker_reward_bps = share_bps;
+    }
+    
+    // Update unbonding period if provided; it applies to later unstakes
+    if let Some(period) = new_unbonding_period {
+        config.unbonding_period = period;
+    }
+    
+    // Update launch fee if provided
+    if let Some(fee) = new_launch_fee {
+        config.launch_fee = fee;
+    }
+    
+    // Referrers, holders and stakers are all paid out of the platform fee
+    let total_share_bps = u32::from(config.referral_share_bps)
+        + u32::from(config.holder_reward_bps)
+        + u32::from(config.staker_reward_bps);
+    if total_share_bps > 10_000 {
+        return Err(ContractError::InvalidFeeShares {});
+    }
+    
     CONFIG.save(deps.storage, &config)?;
     
     Ok(Response::new()
@@ -0,0 +1,105 @@
+use cosmwasm_std::{DepsMut, Env, HexBinary, MessageInfo, Response};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    nft::assert_rights_holder,
+    state::{load_token_metadata, record_metadata_change, token_metadata, FieldChange, SongDetails},
+    metadata::{validate_content_hash, validate_name, validate_uri}
+};
+
+// Set a field, recording the change if the value differs
+fn apply(changes: &mut Vec<FieldChange>, field: &str, current: &mut String, new: String) {
+    if *current != new {
+        changes.push(FieldChange {
+            field: field.to_string(),
+            old: Some(std::mem::replace(current, new.clone())),
+            new: Some(new),
+        });
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_token_metadata(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mint: String,
+    name: Option<String>,
+    music_uri: Option<String>,
+    content_hash: Option<HexBinary>,
+    details: Option<SongDetails>,
+) -> Result<Response, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let mut metadata = load_token_metadata(deps.storage, &denom)?;
+    
+    // Only the token's rights holder edits its metadata, and only until it is frozen
+    assert_rights_holder(&deps.querier, &metadata, &info.sender)?;
+    if metadata.frozen {
+        return Err(ContractError::MetadataFrozen {});
+    }
+    
+    let mut changes = vec![];
+    if let Some(name) = name {
+        validate_name(&name)?;
+        apply(&mut changes, "name", &mut metadata.name, name);
+    }
+    
+    let mut uri_changed = false;
+    if let Some(music_uri) = music_uri {
+        validate_uri("music_uri", &music_uri)?;
+        uri_changed = music_uri != metadata.music_uri;
+        apply(&mut changes, "music_uri", &mut metadata.music_uri, music_uri);
+    }
+    
+    // A new audio file comes with its own hash
+    if let Some(hash) = content_hash {
+        validate_content_hash(&hash)?;
+        if metadata.content_hash.as_ref() != Some(&hash) {
+            changes.push(FieldChange {
+                field: "content_hash".to_string(),
+                old: metadata.content_hash.replace(hash.clone()).map(|old| old.to_hex()),
+                new: Some(hash.to_hex()),
+            });
+        }
+    } else if uri_changed {
+        // The old hash no longer describes the file at the new URI
+        if let Some(old) = metadata.content_hash.take() {
+            changes.push(FieldChange {
+                field: "content_hash".to_string(),
+                old: Some(old.to_hex()),
+                new: None,
+            });
+        }
+    }
+    
+    if let Some(update) = details {
+        let details = metadata.details.merge(update);
+        details.validate()?;
+        changes.extend(details.changes_from(&metadata.details));
+        metadata.details = details;
+    }
+    
+    // Every audit entry records at least one change
+    if changes.is_empty() {
+        return Err(ContractError::NoMetadataChanges {});
+    }
+    
+    // Saving through the indexed map keeps the name index in step
+    token_metadata().save(deps.storage, denom.key(), &metadata)?;
+    
+    let fields = changes.iter().map(|change| change.field.as_str()).collect::<Vec<_>>().join(",");
+    let seq = record_metadata_change(
+        deps.storage,
+        denom.key(),
+        "update",
+        &info.sender,
+        env.block.time.seconds(),
+        changes,
+    )?;
+    
+    Ok(Response::new()
+        .add_attribute("action", "update_token_metadata")
+        .add_attribute("mint", mint)
+        .add_attribute("fields", fields)
+        .add_attribute("seq", seq.to_string()))
+}
@@ -0,0 +1,51 @@
+use cosmwasm_std::{coins, BankMsg, DepsMut, Env, MessageInfo, Response, Uint128};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    state::{add_holdings, assert_not_on_hold, STAKING_POOLS, UNBONDING}
+};
+
+pub fn withdraw_unbonded(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    mint: String,
+) -> Result<Response, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    assert_not_on_hold(deps.storage, &denom)?;
+    let key = (denom.key(), &info.sender);
+    let now = env.block.time.seconds();
+    
+    // Release every entry whose unbonding period has passed
+    let queue = UNBONDING.may_load(deps.storage, key)?.unwrap_or_default();
+    let (released, pending): (Vec<_>, Vec<_>) = queue
+        .into_iter()
+        .partition(|entry| entry.release_time <= now);
+    let amount: Uint128 = released.iter().map(|entry| entry.amount).sum();
+    if amount.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+    
+    if pending.is_empty() {
+        UNBONDING.remove(deps.storage, key);
+    } else {
+        UNBONDING.save(deps.storage, key, &pending)?;
+    }
+    
+    let mut pool = STAKING_POOLS.load(deps.storage, denom.key())?;
+    pool.total_unbonding -= amount;
+    STAKING_POOLS.save(deps.storage, denom.key(), &pool)?;
+    add_holdings(deps.storage, denom.key(), &info.sender, amount)?;
+    
+    let send_msg = BankMsg::Send {
+        to_address: info.sender.to_string(),
+        amount: coins(amount.u128(), denom.as_str()),
+    };
+    
+    Ok(Response::new()
+        .add_message(send_msg)
+        .add_attribute("action", "withdraw_unbonded")
+        .add_attribute("mint", mint)
+        .add_attribute("recipient", info.sender)
+        .add_attribute("amount", amount.to_string()))
+}
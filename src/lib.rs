@@ -1,7 +1,13 @@
+pub mod asset;
 pub mod contract;
+pub mod denom;
 pub mod error;
 pub mod execute;
+pub mod metadata;
+pub mod migrate;
 pub mod msg;
+pub mod nft;
 pub mod query;
 pub mod state;
+pub mod tokenfactory;
 pub mod utils;
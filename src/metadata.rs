@@ -0,0 +1,187 @@
+use cosmwasm_std::HexBinary;
+use crate::{
+    error::ContractError,
+    state::{FieldChange, SongDetails}
+};
+
+// Field limits, counted in characters
+pub const MAX_NAME_CHARS: usize = 32;
+pub const MAX_ARTIST_CHARS: usize = 64;
+pub const MAX_SYMBOL_CHARS: usize = 12;
+pub const MAX_DESCRIPTION_CHARS: usize = 1000;
+pub const MAX_GENRE_CHARS: usize = 32;
+pub const MAX_URI_CHARS: usize = 256;
+pub const MAX_LINKS: usize = 5;
+
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz234567";
+
+// Reject empty or whitespace-only text and text longer than `max` characters
+fn validate_text(field: &str, value: &str, max: usize) -> Result<(), ContractError> {
+    if value.trim().is_empty() {
+        return Err(ContractError::EmptyField { field: field.to_string() });
+    }
+    if value.chars().count() > max {
+        return Err(ContractError::FieldTooLong { field: field.to_string(), max });
+    }
+    
+    Ok(())
+}
+
+// CIDv0 (base58 "Qm...") or CIDv1 in the default base32 encoding ("b...")
+fn is_valid_cid(cid: &str) -> bool {
+    if cid.len() == 46 && cid.starts_with("Qm") {
+        return cid.chars().all(|c| BASE58_ALPHABET.contains(c));
+    }
+    
+    match cid.strip_prefix('b') {
+        Some(rest) => rest.len() >= 50 && rest.chars().all(|c| BASE32_ALPHABET.contains(c)),
+        None => false,
+    }
+}
+
+// Arweave transaction ids are 43 base64url characters
+fn is_valid_arweave_id(id: &str) -> bool {
+    id.len() == 43 && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+// Accept ipfs://, ar:// and https:// URIs; content-addressed ones must name a valid id
+pub fn validate_uri(field: &str, uri: &str) -> Result<(), ContractError> {
+    validate_text(field, uri, MAX_URI_CHARS)?;
+    if uri.chars().any(char::is_whitespace) {
+        return Err(ContractError::InvalidUri { field: field.to_string() });
+    }
+    
+    let valid = if let Some(path) = uri.strip_prefix("ipfs://") {
+        is_valid_cid(path.split('/').next().unwrap_or_default())
+    } else if let Some(path) = uri.strip_prefix("ar://") {
+        is_valid_arweave_id(path.split('/').next().unwrap_or_default())
+    } else if let Some(rest) = uri.strip_prefix("https://") {
+        !rest.split('/').next().unwrap_or_default().is_empty()
+    } else {
+        false
+    };
+    if !valid {
+        return Err(ContractError::InvalidUri { field: field.to_string() });
+    }
+    
+    Ok(())
+}
+
+pub fn validate_content_hash(hash: &HexBinary) -> Result<(), ContractError> {
+    if hash.len() != 32 {
+        return Err(ContractError::InvalidContentHash {});
+    }
+    
+    Ok(())
+}
+
+pub fn validate_name(name: &str) -> Result<(), ContractError> {
+    validate_text("name", name, MAX_NAME_CHARS)
+}
+
+// ISRC codes are 12 characters: country, registrant, year and designation, e.g. USRC17607839
+fn is_valid_isrc(isrc: &str) -> bool {
+    let bytes = isrc.as_bytes();
+    bytes.len() == 12
+        && bytes[..2].iter().all(u8::is_ascii_uppercase)
+        && bytes[2..5].iter().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit())
+        && bytes[5..].iter().all(u8::is_ascii_digit)
+}
+
+// A new value replaces the current one and an empty one clears it
+fn merge_text(update: Option<String>, current: &Option<String>) -> Option<String> {
+    match update {
+        Some(value) if value.is_empty() => None,
+        Some(value) => Some(value),
+        None => current.clone(),
+    }
+}
+
+impl SongDetails {
+    pub fn validate(&self) -> Result<(), ContractError> {
+        if let Some(artist) = &self.artist {
+            validate_text("artist", artist, MAX_ARTIST_CHARS)?;
+        }
+        if let Some(symbol) = &self.symbol {
+            validate_text("symbol", symbol, MAX_SYMBOL_CHARS)?;
+            if !symbol.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(ContractError::InvalidMetadata { field: "symbol".to_string() });
+            }
+        }
+        if let Some(description) = &self.description {
+            validate_text("description", description, MAX_DESCRIPTION_CHARS)?;
+        }
+        if let Some(cover_uri) = &self.cover_uri {
+            validate_uri("cover_uri", cover_uri)?;
+        }
+        if let Some(isrc) = &self.isrc {
+            if !is_valid_isrc(isrc) {
+                return Err(ContractError::InvalidMetadata { field: "isrc".to_string() });
+            }
+        }
+        if let Some(genre) = &self.genre {
+            validate_text("genre", genre, MAX_GENRE_CHARS)?;
+        }
+        if self.duration_seconds == Some(0) {
+            return Err(ContractError::InvalidMetadata { field: "duration_seconds".to_string() });
+        }
+        if let Some(links) = &self.links {
+            if links.len() > MAX_LINKS {
+                return Err(ContractError::TooManyLinks { max: MAX_LINKS });
+            }
+            for link in links {
+                validate_uri("links", link)?;
+            }
+        }
+    
+        Ok(())
+    }
+    
+    // Overwrite the fields set in `update`; an empty string, a zero duration or an empty links
+    // list clears the field
+    pub fn merge(&self, update: SongDetails) -> SongDetails {
+        SongDetails {
+            artist: merge_text(update.artist, &self.artist),
+            symbol: merge_text(update.symbol, &self.symbol),
+            description: merge_text(update.description, &self.description),
+            cover_uri: merge_text(update.cover_uri, &self.cover_uri),
+            isrc: merge_text(update.isrc, &self.isrc),
+            genre: merge_text(update.genre, &self.genre),
+            duration_seconds: match update.duration_seconds {
+                Some(0) => None,
+                Some(seconds) => Some(seconds),
+                None => self.duration_seconds,
+            },
+            links: match update.links {
+                Some(links) if links.is_empty() => None,
+                Some(links) => Some(links),
+                None => self.links.clone(),
+            },
+        }
+    }
+    
+    // Field values as text, for the audit trail
+    fn entries(&self) -> [(&'static str, Option<String>); 8] {
+        [
+            ("artist", self.artist.clone()),
+            ("symbol", self.symbol.clone()),
+            ("description", self.description.clone()),
+            ("cover_uri", self.cover_uri.clone()),
+            ("isrc", self.isrc.clone()),
+            ("genre", self.genre.clone()),
+            ("duration_seconds", self.duration_seconds.map(|seconds| seconds.to_string())),
+            ("links", self.links.as_ref().map(|links| links.join(" "))),
+        ]
+    }
+    
+    // Fields that differ between two versions of the details
+    pub fn changes_from(&self, old: &SongDetails) -> Vec<FieldChange> {
+        old.entries()
+            .into_iter()
+            .zip(self.entries())
+            .filter(|((_, old), (_, new))| old != new)
+            .map(|((field, old), (_, new))| FieldChange { field: field.to_string(), old, new })
+            .collect()
+    }
+}
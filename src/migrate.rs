@@ -0,0 +1,45 @@
+use cosmwasm_std::{DepsMut, Env, Order, Response, StdResult};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::MigrateMsg,
+    state::{token_metadata, TokenStatus, TOKEN_STATUS, TOKEN_VAULTS}
+};
+
+// Bring storage written by earlier versions up to date: record each token's creator, build the
+// metadata indexes and give every token a lifecycle status. Safe to run more than once.
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let tokens = token_metadata()
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    
+    for (key, mut metadata) in tokens.iter().cloned() {
+        let denom = FactoryDenom::parse(deps.api, &metadata.mint)?;
+        if metadata.creator.as_str().is_empty() {
+            metadata.creator = denom.creator.clone();
+        }
+        
+        // Ids were not unique before they were indexed; a clash has to be fixed by hand
+        if let Some((owner, _)) = token_metadata().idx.id.item(deps.storage, metadata.id)? {
+            if owner != key {
+                return Err(ContractError::TokenIdTaken { id: metadata.id });
+            }
+        }
+        
+        // Saving through the indexed map writes the id, creator and name indexes
+        token_metadata().save(deps.storage, &key, &metadata)?;
+        
+        // Tokens from before statuses existed are live once their vault is set up
+        if !TOKEN_STATUS.has(deps.storage, &key) {
+            let ready = TOKEN_VAULTS
+                .may_load(deps.storage, &key)?
+                .is_some_and(|vault| vault.vault_account.is_some());
+            let status = if ready { TokenStatus::Live } else { TokenStatus::Draft };
+            TOKEN_STATUS.save(deps.storage, &key, &status)?;
+        }
+    }
+    
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("tokens", tokens.len().to_string()))
+}
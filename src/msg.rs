@@ -1,6 +1,7 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use cosmwasm_std::{Uint128};
+use cosmwasm_std::{Coin, Decimal, HexBinary, Uint128};
+use crate::state::{Airdrop, Delisting, Dispute, HoldOutcome, LaunchConfig, MasterNft, MetadataChange, RewardPool, SongDetails, StakingPool, Unbonding, SplitRecipient, TokenMetadata, TokenStatus, TokenVault, ViewershipOracle};
 use cw20::Cw20ReceiveMsg;
 use cosmwasm_schema::{QueryResponses};
 
@@ -8,8 +9,18 @@ use cosmwasm_schema::{QueryResponses};
 pub struct InstantiateMsg {
     pub platform_wallet: String,
     pub oracle_authority: String,
+    pub referral_share_bps: Option<u16>,
+    pub holder_reward_bps: Option<u16>,
+    pub staker_reward_bps: Option<u16>,
+    pub unbonding_period: Option<u64>,
+    pub launch_fee: Option<Uint128>, // INJ charged by LaunchToken
+    pub moderator: Option<String>,
 }
 
+// Upgrades take no parameters; migrate() brings older storage up to date
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MigrateMsg {}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
@@ -17,6 +28,11 @@ pub enum ExecuteMsg {
     UpdatePlatform {
         new_platform_wallet: String,
         new_oracle_authority: Option<String>,
+        new_referral_share_bps: Option<u16>,
+        new_holder_reward_bps: Option<u16>,
+        new_staker_reward_bps: Option<u16>,
+        new_unbonding_period: Option<u64>,
+        new_launch_fee: Option<Uint128>,
     },
     
     // Token setup
@@ -24,11 +40,52 @@ pub enum ExecuteMsg {
         id: u64,
         name: String,
         music_uri: String,
+        // sha256 of the audio file at music_uri
+        content_hash: Option<HexBinary>,
         mint: String, // CW20 token address
+        creator: Option<String>, // Royalty recipient, defaults to the denom creator
+        royalty_bps: u16,
+        // Creator pre-mine, deposited in the mint denom with this message
+        vesting: Option<VestingParams>,
+        // Mint a master NFT to the creator that carries the token's creator rights
+        mint_master_nft: Option<bool>,
+        details: Option<SongDetails>,
     },
     InitializeTokenOracle {
         mint: String,
     },
+    
+    // Create factory/{contract}/{subdenom} with its bank metadata and set up its metadata,
+    // oracle, vault and launch config in one step, charging the launch fee. `initial_buy` tokens
+    // are bought for the sender before the launch config is saved, so launch rules (start time,
+    // allowlist, caps) do not apply to them
+    LaunchToken {
+        subdenom: String,
+        id: u64,
+        name: String,
+        music_uri: String,
+        content_hash: Option<HexBinary>,
+        creator: Option<String>, // Royalty recipient, defaults to the sender
+        royalty_bps: u16,
+        mint_master_nft: Option<bool>,
+        details: Option<SongDetails>,
+        launch: Option<LaunchParams>,
+        initial_buy: Option<Uint128>,
+    },
+    
+    // Metadata edits by the token's rights holder until it is frozen
+    UpdateTokenMetadata {
+        mint: String,
+        name: Option<String>,
+        music_uri: Option<String>,
+        content_hash: Option<HexBinary>,
+        // Fields set here replace the stored ones; an empty string, a zero duration or an empty
+        // links list removes the field
+        details: Option<SongDetails>,
+    },
+    FreezeMetadata {
+        mint: String,
+    },
     SetupVaultAccount {
         mint: String,
     },
@@ -41,6 +98,104 @@ pub enum ExecuteMsg {
     PurchaseToken {
         mint: String,
         amount_tokens: Uint128,
+        referrer: Option<String>,
+        // Merkle proof of the sender's address, required during a presale
+        proof: Option<Vec<HexBinary>>,
+    },
+    
+    // Revenue split
+    SetRevenueSplit {
+        mint: String,
+        recipients: Vec<SplitRecipientMsg>,
+    },
+    
+    // Launch protections, set by the creator before the first purchase
+    SetLaunchConfig {
+        mint: String,
+        start_time: u64,
+        allowlist_duration: Option<u64>,
+        protection_duration: u64,
+        max_per_address: Option<Uint128>,
+        max_per_tx: Option<Uint128>,
+        presale: Option<PresaleParams>,
+    },
+    UpdateLaunchAllowlist {
+        mint: String,
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
+    
+    // Creator vesting
+    ClaimVested {
+        mint: String,
+    },
+    
+    // Holder rewards paid in INJ from a share of platform fees
+    ClaimHolderRewards {
+        mint: String,
+    },
+    
+    // Staking of a song denom; stakers earn INJ from a share of its platform fees
+    Stake {
+        mint: String,
+    },
+    Unstake {
+        mint: String,
+        amount: Uint128,
+    },
+    WithdrawUnbonded {
+        mint: String,
+    },
+    ClaimStakingRewards {
+        mint: String,
+    },
+    
+    // Moderator who places and resolves dispute holds
+    SetModerator {
+        moderator: Option<String>,
+    },
+    
+    // CW721 collection for master NFTs; the contract must be its minter
+    SetMasterNftContract {
+        contract: Option<String>,
+    },
+    
+    // Listener airdrops; the creator deposits total_amount of the mint
+    RegisterAirdrop {
+        mint: String,
+        merkle_root: HexBinary,
+        total_amount: Uint128,
+        expiry: u64,
+    },
+    ClaimAirdrop {
+        mint: String,
+        amount: Uint128,
+        proof: Vec<HexBinary>,
+    },
+    // Return the unclaimed remainder to the creator after expiry
+    ReclaimAirdrop {
+        mint: String,
+    },
+    
+    // Accrued fees and royalties; CW20 balances use "cw20:{contract}" as denom
+    ClaimFees {
+        denom: String,
+    },
+    
+    // CW20 quote tokens accepted as payment
+    AddQuoteToken {
+        token: String,
+        inj_rate: Decimal,
+    },
+    RemoveQuoteToken {
+        token: String,
+    },
+    
+    // Return funds stuck in the contract that are not owed to anyone
+    RecoverTokens {
+        asset: String, // Native denom or "cw20:{contract}"
+        amount: Uint128,
+        recipient: String,
     },
     
     // New mint function
@@ -50,10 +205,85 @@ pub enum ExecuteMsg {
         amount: Uint128,
     },
     
+    // Authority records the DEX pool a graduating token's liquidity moved to
+    CompleteGraduation {
+        mint: String,
+        dex_pool: String,
+    },
+    
+    // Authority takes a song down, halting trading; the vault's collected INJ must be sent with it
+    // and becomes the redemption reserve
+    DelistToken {
+        mint: String,
+        reason_uri: Option<String>,
+    },
+    // Burn delisted tokens, sent with this message, for a pro-rata share of the reserve
+    RedeemDelisted {
+        mint: String,
+    },
+    
+    // Moderator freezes a disputed song's trading and reserve
+    PlaceHold {
+        mint: String,
+        reason_uri: String,
+    },
+    // Moderator releases the hold or turns it into a delisting; a delisting must be sent the
+    // vault's collected INJ, which becomes the redemption reserve
+    ResolveHold {
+        mint: String,
+        outcome: HoldOutcome,
+        resolution_uri: Option<String>,
+    },
+    
     // CW20 receive function for token transfers
     Receive(Cw20ReceiveMsg),
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VestingParams {
+    pub amount: Uint128,
+    pub cliff_seconds: u64,
+    pub duration_seconds: u64,
+}
+
+// Messages embedded in Cw20ReceiveMsg.msg
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReceiveMsg {
+    Purchase {
+        mint: String,
+        min_tokens_out: Uint128,
+        referrer: Option<String>,
+        proof: Option<Vec<HexBinary>>,
+    },
+}
+
+// Launch protections as set by SetLaunchConfig
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LaunchParams {
+    pub start_time: u64,
+    pub allowlist_duration: Option<u64>,
+    pub protection_duration: u64,
+    pub max_per_address: Option<Uint128>,
+    pub max_per_tx: Option<Uint128>,
+    pub presale: Option<PresaleParams>,
+}
+
+// Presale ahead of a launch; it runs for `duration` seconds before start_time
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PresaleParams {
+    pub merkle_root: HexBinary,
+    pub duration: u64,
+    pub discount_bps: u16,
+    pub max_per_address: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SplitRecipientMsg {
+    pub address: String,
+    pub weight_bps: u16,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, QueryResponses)]
 #[serde(rename_all = "snake_case")]
 pub enum QueryMsg {
@@ -63,11 +293,112 @@ pub enum QueryMsg {
     #[returns(TokenMetadataResponse)]
     GetTokenMetadata { mint: String },
     
+    #[returns(MetadataHistoryResponse)]
+    GetMetadataHistory {
+        mint: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    
+    // Whether a file hash matches the audio the token was launched against
+    #[returns(VerifyContentResponse)]
+    VerifyContent { mint: String, hash: HexBinary },
+    
     #[returns(TokenPriceResponse)]
     GetTokenPrice { mint: String },
     
     #[returns(VaultBalanceResponse)]
     GetVaultBalance { mint: String },
+    
+    #[returns(ListTokensResponse)]
+    ListTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    
+    #[returns(TokenMetadataResponse)]
+    GetTokenById { id: u64 },
+    
+    #[returns(ListTokensResponse)]
+    ListTokensByCreator {
+        creator: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    
+    #[returns(ListTokensResponse)]
+    SearchTokensByNamePrefix {
+        prefix: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    
+    #[returns(SimulatePurchaseResponse)]
+    SimulatePurchase { mint: String, amount_tokens: Uint128 },
+    
+    #[returns(SimulatePurchaseResponse)]
+    SimulatePurchaseExactIn { mint: String, funds: Uint128 },
+    
+    #[returns(TokenStateResponse)]
+    GetTokenState { mint: String },
+    
+    #[returns(TokenStatusResponse)]
+    GetTokenStatus { mint: String },
+    
+    #[returns(RoyaltyTotalResponse)]
+    GetTokenRoyalties { mint: String },
+    
+    #[returns(RoyaltyTotalResponse)]
+    GetCreatorRoyalties { creator: String },
+    
+    #[returns(RevenueSplitResponse)]
+    GetRevenueSplit { mint: String },
+    
+    #[returns(ClaimableResponse)]
+    GetClaimable { address: String },
+    
+    #[returns(ReferralStatsResponse)]
+    GetReferralStats { address: String },
+    
+    #[returns(QuoteTokensResponse)]
+    ListQuoteTokens {},
+    
+    #[returns(VestingResponse)]
+    GetVesting { mint: String, address: String },
+    
+    #[returns(LaunchConfigResponse)]
+    GetLaunchConfig { mint: String },
+    
+    #[returns(PresaleAllocationResponse)]
+    GetPresaleAllocation { mint: String, address: String },
+    
+    #[returns(AirdropResponse)]
+    GetAirdrop { mint: String },
+    
+    #[returns(AirdropClaimResponse)]
+    GetAirdropClaim { mint: String, address: String },
+    
+    #[returns(RewardPoolResponse)]
+    GetRewardPool { mint: String },
+    
+    #[returns(HolderRewardsResponse)]
+    GetHolderRewards { mint: String, address: String },
+    
+    #[returns(StakingPoolResponse)]
+    GetStakingPool { mint: String },
+    
+    #[returns(StakedPositionResponse)]
+    GetStakedPosition { mint: String, address: String },
+    
+    #[returns(DelistingResponse)]
+    GetDelisting { mint: String },
+    
+    #[returns(DisputesResponse)]
+    GetDisputes {
+        mint: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 // Response types
@@ -76,14 +407,55 @@ pub struct PlatformConfigResponse {
     pub platform_wallet: String,
     pub oracle_authority: String,
     pub authority: String,
+    pub referral_share_bps: u16,
+    pub holder_reward_bps: u16,
+    pub staker_reward_bps: u16,
+    pub unbonding_period: u64,
+    pub master_nft_contract: Option<String>,
+    pub launch_fee: Uint128,
+    pub moderator: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct TokenMetadataResponse {
     pub name: String,
     pub music_uri: String,
+    pub content_hash: Option<HexBinary>,
     pub id: u64,
     pub mint: String,
+    pub creator: String,
+    pub royalty_bps: u16,
+    pub master_nft: Option<MasterNft>,
+    pub details: SongDetails,
+    pub frozen: bool,
+}
+
+impl From<TokenMetadata> for TokenMetadataResponse {
+    fn from(metadata: TokenMetadata) -> Self {
+        TokenMetadataResponse {
+            name: metadata.name,
+            music_uri: metadata.music_uri,
+            content_hash: metadata.content_hash,
+            id: metadata.id,
+            mint: metadata.mint,
+            creator: metadata.creator.to_string(),
+            royalty_bps: metadata.royalty_bps,
+            master_nft: metadata.master_nft,
+            details: metadata.details,
+            frozen: metadata.frozen,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MetadataHistoryResponse {
+    pub entries: Vec<MetadataChange>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VerifyContentResponse {
+    pub matches: bool,
+    pub content_hash: Option<HexBinary>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -95,3 +467,146 @@ pub struct TokenPriceResponse {
 pub struct VaultBalanceResponse {
     pub balance: Uint128,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TokenInfoResponse {
+    pub metadata: TokenMetadataResponse,
+    pub price: Option<Uint128>,
+    pub oracle: Option<ViewershipOracle>,
+    pub vault: Option<TokenVault>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ListTokensResponse {
+    pub tokens: Vec<TokenInfoResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SimulatePurchaseResponse {
+    pub amount_tokens: Uint128,
+    pub price_per_token: Uint128,
+    pub total_cost: Uint128,
+    pub platform_fee: Uint128,
+    pub royalty: Uint128,
+    pub vault_amount: Uint128,
+    pub resulting_supply: Uint128,
+    pub crosses_liquidity_threshold: bool,
+    pub status: TokenStatus,
+    // Whether the quote is at the presale price; presale buyers must be on its allowlist
+    pub presale: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TokenStateResponse {
+    pub metadata: TokenMetadataResponse,
+    pub oracle: ViewershipOracle,
+    pub vault: TokenVault,
+    pub circulating_supply: Uint128,
+    pub graduated: bool,
+    pub status: TokenStatus,
+    pub price: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TokenStatusResponse {
+    pub status: TokenStatus,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RoyaltyTotalResponse {
+    pub total: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RevenueSplitResponse {
+    pub recipients: Vec<SplitRecipient>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ClaimableResponse {
+    pub balances: Vec<Coin>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ReferralStatsResponse {
+    pub total_earned: Uint128,
+    pub referred_purchases: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct QuoteTokenResponse {
+    pub token: String,
+    pub inj_rate: Decimal,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct QuoteTokensResponse {
+    pub tokens: Vec<QuoteTokenResponse>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VestingResponse {
+    pub total: Uint128,
+    pub claimed: Uint128,
+    pub vested: Uint128,
+    pub claimable: Uint128,
+    pub start_time: u64,
+    pub cliff_time: u64,
+    pub end_time: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LaunchConfigResponse {
+    pub config: Option<LaunchConfig>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PresaleAllocationResponse {
+    pub purchased: Uint128,
+    pub remaining: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AirdropResponse {
+    pub airdrop: Airdrop,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AirdropClaimResponse {
+    pub claimed: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RewardPoolResponse {
+    pub pool: RewardPool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct HolderRewardsResponse {
+    pub holdings: Uint128,
+    // Earned, and claimable after the next view update
+    pub pending: Uint128,
+    pub claimable: Uint128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StakingPoolResponse {
+    pub pool: StakingPool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct StakedPositionResponse {
+    pub staked: Uint128,
+    pub claimable_rewards: Uint128,
+    pub unbonding: Vec<Unbonding>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DelistingResponse {
+    pub delisting: Option<Delisting>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DisputesResponse {
+    pub disputes: Vec<Dispute>,
+}
@@ -0,0 +1,90 @@
+use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, Empty, QuerierWrapper, StdResult, WasmMsg};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::ContractError,
+    state::{MasterNft, TokenMetadata}
+};
+
+// The subset of the CW721 interface the contract uses, matching cw721-base on the wire
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum Cw721ExecuteMsg {
+    Mint {
+        token_id: String,
+        owner: String,
+        token_uri: Option<String>,
+        extension: Empty,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+enum Cw721QueryMsg {
+    OwnerOf {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+struct OwnerOfResponse {
+    owner: String,
+}
+
+impl MasterNft {
+    // Mint the master NFT to the artist; the contract must be the collection's minter
+    pub fn mint_msg(&self, owner: &Addr, token_uri: &str) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: self.contract.to_string(),
+            msg: to_json_binary(&Cw721ExecuteMsg::Mint {
+                token_id: self.token_id.clone(),
+                owner: owner.to_string(),
+                token_uri: Some(token_uri.to_string()),
+                extension: Empty {},
+            })?,
+            funds: vec![],
+        }
+        .into())
+    }
+    
+    pub fn query_owner(&self, querier: &QuerierWrapper) -> StdResult<Addr> {
+        let response: OwnerOfResponse = querier.query_wasm_smart(
+            &self.contract,
+            &Cw721QueryMsg::OwnerOf {
+                token_id: self.token_id.clone(),
+                include_expired: None,
+            },
+        )?;
+        
+        Ok(Addr::unchecked(response.owner))
+    }
+}
+
+// Holder of a token's creator rights: the master NFT owner if the token has one, else its creator
+pub fn rights_holder(querier: &QuerierWrapper, metadata: &TokenMetadata) -> StdResult<Addr> {
+    match &metadata.master_nft {
+        Some(nft) => nft.query_owner(querier),
+        None => Ok(metadata.creator.clone()),
+    }
+}
+
+// Royalty recipient for a purchase; falls back to the creator if the master NFT cannot be
+// queried (burned, or a misbehaving collection) so purchases never depend on the collection
+pub fn royalty_recipient(querier: &QuerierWrapper, metadata: &TokenMetadata) -> Addr {
+    rights_holder(querier, metadata).unwrap_or_else(|_| metadata.creator.clone())
+}
+
+// Reject senders that do not hold the token's creator rights
+pub fn assert_rights_holder(
+    querier: &QuerierWrapper,
+    metadata: &TokenMetadata,
+    sender: &Addr,
+) -> Result<(), ContractError> {
+    if rights_holder(querier, metadata)? != *sender {
+        return Err(ContractError::Unauthorized {});
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use cosmwasm_std::Deps;
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::AirdropResponse,
+    state::AIRDROPS
+};
+
+pub fn get_airdrop(deps: Deps, mint: String) -> Result<AirdropResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    
+    let airdrop = AIRDROPS
+        .may_load(deps.storage, denom.key())?
+        .ok_or(ContractError::AirdropNotFound { mint })?;
+    
+    Ok(AirdropResponse { airdrop })
+}
@@ -0,0 +1,23 @@
+use cosmwasm_std::Deps;
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::AirdropClaimResponse,
+    state::AIRDROP_CLAIMS
+};
+
+pub fn get_airdrop_claim(
+    deps: Deps,
+    mint: String,
+    address: String,
+) -> Result<AirdropClaimResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let address = deps.api.addr_validate(&address)?;
+    
+    // Zero until the address claims its allocation
+    let claimed = AIRDROP_CLAIMS
+        .may_load(deps.storage, (denom.key(), &address))?
+        .unwrap_or_default();
+    
+    Ok(AirdropClaimResponse { claimed })
+}
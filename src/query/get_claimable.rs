@@ -0,0 +1,20 @@
+use cosmwasm_std::{Coin, Deps, Order, StdResult};
+use crate::{
+    msg::ClaimableResponse,
+    state::CLAIMABLE
+};
+
+pub fn get_claimable(deps: Deps, address: String) -> StdResult<ClaimableResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    
+    let balances = CLAIMABLE
+        .prefix(&address)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(Coin { denom, amount })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    
+    Ok(ClaimableResponse { balances })
+}
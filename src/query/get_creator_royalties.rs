@@ -0,0 +1,12 @@
+use cosmwasm_std::{Deps, StdResult};
+use crate::{
+    msg::RoyaltyTotalResponse,
+    state::CREATOR_ROYALTIES
+};
+
+pub fn get_creator_royalties(deps: Deps, creator: String) -> StdResult<RoyaltyTotalResponse> {
+    let creator = deps.api.addr_validate(&creator)?;
+    let total = CREATOR_ROYALTIES.may_load(deps.storage, &creator)?.unwrap_or_default();
+    
+    Ok(RoyaltyTotalResponse { total })
+}
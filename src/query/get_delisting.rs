@@ -0,0 +1,17 @@
+use cosmwasm_std::Deps;
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::DelistingResponse,
+    state::{load_token_metadata, DELISTINGS}
+};
+
+pub fn get_delisting(deps: Deps, mint: String) -> Result<DelistingResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    load_token_metadata(deps.storage, &denom)?;
+    
+    // Tokens that were never delisted have no redemption terms
+    let delisting = DELISTINGS.may_load(deps.storage, denom.key())?;
+    
+    Ok(DelistingResponse { delisting })
+}
@@ -0,0 +1,29 @@
+use cosmwasm_std::{Deps, Order, StdResult};
+use cw_storage_plus::Bound;
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::DisputesResponse,
+    state::{load_token_metadata, DISPUTES}
+};
+use super::list_tokens::page_limit;
+
+pub fn get_disputes(
+    deps: Deps,
+    mint: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<DisputesResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    load_token_metadata(deps.storage, &denom)?;
+    
+    // Oldest hold first
+    let disputes = DISPUTES
+        .prefix(denom.key())
+        .range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+        .take(page_limit(limit))
+        .map(|item| item.map(|(_, dispute)| dispute))
+        .collect::<StdResult<Vec<_>>>()?;
+    
+    Ok(DisputesResponse { disputes })
+}
@@ -0,0 +1,25 @@
+use cosmwasm_std::Deps;
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::HolderRewardsResponse,
+    state::{load_holder_rewards, REWARD_POOLS}
+};
+
+pub fn get_holder_rewards(
+    deps: Deps,
+    mint: String,
+    address: String,
+) -> Result<HolderRewardsResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let address = deps.api.addr_validate(&address)?;
+    
+    let pool = REWARD_POOLS.may_load(deps.storage, denom.key())?.unwrap_or_default();
+    let rewards = load_holder_rewards(deps.storage, denom.key(), &address, &pool)?;
+    
+    Ok(HolderRewardsResponse {
+        holdings: rewards.holdings,
+        pending: rewards.pending,
+        claimable: rewards.accrued,
+    })
+}
@@ -0,0 +1,17 @@
+use cosmwasm_std::Deps;
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::LaunchConfigResponse,
+    state::{load_token_metadata, LAUNCH_CONFIGS}
+};
+
+pub fn get_launch_config(deps: Deps, mint: String) -> Result<LaunchConfigResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    load_token_metadata(deps.storage, &denom)?;
+    
+    // Tokens without a launch config trade without restrictions
+    let config = LAUNCH_CONFIGS.may_load(deps.storage, denom.key())?;
+    
+    Ok(LaunchConfigResponse { config })
+}
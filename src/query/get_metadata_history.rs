@@ -0,0 +1,29 @@
+use cosmwasm_std::{Deps, Order, StdResult};
+use cw_storage_plus::Bound;
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::MetadataHistoryResponse,
+    state::{load_token_metadata, METADATA_HISTORY}
+};
+use super::list_tokens::page_limit;
+
+pub fn get_metadata_history(
+    deps: Deps,
+    mint: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<MetadataHistoryResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    load_token_metadata(deps.storage, &denom)?;
+    
+    // Oldest change first
+    let entries = METADATA_HISTORY
+        .prefix(denom.key())
+        .range(deps.storage, start_after.map(Bound::exclusive), None, Order::Ascending)
+        .take(page_limit(limit))
+        .map(|item| item.map(|(_, change)| change))
+        .collect::<StdResult<Vec<_>>>()?;
+    
+    Ok(MetadataHistoryResponse { entries })
+}
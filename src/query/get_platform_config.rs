@@ -11,5 +11,12 @@ pub fn get_platform_config(deps: Deps) -> StdResult<PlatformConfigResponse> {
         platform_wallet: config.platform_wallet.to_string(),
         oracle_authority: config.oracle_authority.to_string(),
         authority: config.authority.to_string(),
+        referral_share_bps: config.referral_share_bps,
+        holder_reward_bps: config.holder_reward_bps,
+        staker_reward_bps: config.staker_reward_bps,
+        unbonding_period: config.unbonding_period,
+        master_nft_contract: config.master_nft_contract.map(|contract| contract.to_string()),
+        launch_fee: config.launch_fee,
+        moderator: config.moderator.map(|moderator| moderator.to_string()),
     })
 }
@@ -0,0 +1,33 @@
+use cosmwasm_std::Deps;
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::PresaleAllocationResponse,
+    state::{load_token_metadata, LAUNCH_CONFIGS, PRESALE_PURCHASES}
+};
+
+pub fn get_presale_allocation(
+    deps: Deps,
+    mint: String,
+    address: String,
+) -> Result<PresaleAllocationResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let address = deps.api.addr_validate(&address)?;
+    load_token_metadata(deps.storage, &denom)?;
+    
+    let purchased = PRESALE_PURCHASES
+        .may_load(deps.storage, (denom.key(), &address))?
+        .unwrap_or_default();
+    
+    // Remaining is the cap left to buy, whether or not the address is on the allowlist
+    let max_per_address = LAUNCH_CONFIGS
+        .may_load(deps.storage, denom.key())?
+        .and_then(|launch| launch.presale)
+        .map(|presale| presale.max_per_address)
+        .unwrap_or_default();
+    
+    Ok(PresaleAllocationResponse {
+        purchased,
+        remaining: max_per_address.saturating_sub(purchased),
+    })
+}
@@ -0,0 +1,15 @@
+use cosmwasm_std::{Deps, StdResult};
+use crate::{
+    msg::ReferralStatsResponse,
+    state::REFERRAL_STATS
+};
+
+pub fn get_referral_stats(deps: Deps, address: String) -> StdResult<ReferralStatsResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let stats = REFERRAL_STATS.may_load(deps.storage, &address)?.unwrap_or_default();
+    
+    Ok(ReferralStatsResponse {
+        total_earned: stats.total_earned,
+        referred_purchases: stats.referred_purchases,
+    })
+}
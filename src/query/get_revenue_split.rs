@@ -0,0 +1,17 @@
+use cosmwasm_std::Deps;
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::RevenueSplitResponse,
+    state::{load_token_metadata, REVENUE_SPLITS}
+};
+
+pub fn get_revenue_split(deps: Deps, mint: String) -> Result<RevenueSplitResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    load_token_metadata(deps.storage, &denom)?;
+    
+    // Without a split the whole royalty goes to the creator
+    let recipients = REVENUE_SPLITS.may_load(deps.storage, denom.key())?.unwrap_or_default();
+    
+    Ok(RevenueSplitResponse { recipients })
+}
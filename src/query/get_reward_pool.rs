@@ -0,0 +1,17 @@
+use cosmwasm_std::Deps;
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::RewardPoolResponse,
+    state::{load_token_metadata, REWARD_POOLS}
+};
+
+pub fn get_reward_pool(deps: Deps, mint: String) -> Result<RewardPoolResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    load_token_metadata(deps.storage, &denom)?;
+    
+    // Pools are created by the first purchase
+    let pool = REWARD_POOLS.may_load(deps.storage, denom.key())?.unwrap_or_default();
+    
+    Ok(RewardPoolResponse { pool })
+}
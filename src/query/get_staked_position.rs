@@ -0,0 +1,28 @@
+use cosmwasm_std::Deps;
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::StakedPositionResponse,
+    state::{load_staker, STAKING_POOLS, UNBONDING}
+};
+
+pub fn get_staked_position(
+    deps: Deps,
+    mint: String,
+    address: String,
+) -> Result<StakedPositionResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let address = deps.api.addr_validate(&address)?;
+    
+    let pool = STAKING_POOLS.may_load(deps.storage, denom.key())?.unwrap_or_default();
+    let staker = load_staker(deps.storage, denom.key(), &address, &pool)?;
+    let unbonding = UNBONDING
+        .may_load(deps.storage, (denom.key(), &address))?
+        .unwrap_or_default();
+    
+    Ok(StakedPositionResponse {
+        staked: staker.staked,
+        claimable_rewards: staker.accrued,
+        unbonding,
+    })
+}
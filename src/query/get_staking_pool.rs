@@ -0,0 +1,17 @@
+use cosmwasm_std::Deps;
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::StakingPoolResponse,
+    state::{load_token_metadata, STAKING_POOLS}
+};
+
+pub fn get_staking_pool(deps: Deps, mint: String) -> Result<StakingPoolResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    load_token_metadata(deps.storage, &denom)?;
+    
+    // Pools are created by the first stake
+    let pool = STAKING_POOLS.may_load(deps.storage, denom.key())?.unwrap_or_default();
+    
+    Ok(StakingPoolResponse { pool })
+}
@@ -0,0 +1,15 @@
+use cosmwasm_std::{Deps, StdError, StdResult};
+use crate::{
+    msg::TokenMetadataResponse,
+    state::token_metadata
+};
+
+pub fn get_token_by_id(deps: Deps, id: u64) -> StdResult<TokenMetadataResponse> {
+    let (_, metadata) = token_metadata()
+        .idx
+        .id
+        .item(deps.storage, id)?
+        .ok_or_else(|| StdError::not_found(format!("token with id {}", id)))?;
+    
+    Ok(TokenMetadataResponse::from(metadata))
+}
@@ -1,28 +1,14 @@
-use cosmwasm_std::{Deps, StdResult};
+use cosmwasm_std::Deps;
 use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
     msg::TokenMetadataResponse,
-    state::TOKEN_METADATA
+    state::load_token_metadata
 };
 
-pub fn get_token_metadata(deps: Deps, mint: String) -> StdResult<TokenMetadataResponse> {
-    // Create an owned Vec<u8> for the key
-    let key: Vec<u8>;
+pub fn get_token_metadata(deps: Deps, mint: String) -> Result<TokenMetadataResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let metadata = load_token_metadata(deps.storage, &denom)?;
     
-    if mint.starts_with("factory/") {
-        // For factory denoms, use the string bytes directly
-        key = mint.as_bytes().to_vec();
-    } else {
-        // For regular addresses, validate and convert to bytes
-        let mint_addr = deps.api.addr_validate(&mint)?;
-        key = mint_addr.as_bytes().to_vec();
-    }
-    
-    let metadata = TOKEN_METADATA.load(deps.storage, &key)?;
-    
-    Ok(TokenMetadataResponse {
-        name: metadata.name,
-        music_uri: metadata.music_uri,
-        id: metadata.id,
-        mint: metadata.mint.to_string(),
-    })
-}
\ No newline at end of file
+    Ok(TokenMetadataResponse::from(metadata))
+}
@@ -1,28 +1,18 @@
-use cosmwasm_std::{Deps, StdResult, Uint128};
+use cosmwasm_std::Deps;
 use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
     msg::TokenPriceResponse,
-    state::VIEWERSHIP_ORACLES,
+    state::{load_oracle, TOKEN_SUPPLY},
     utils::calculate_price
 };
 
-pub fn get_token_price(deps: Deps, mint: String) -> StdResult<TokenPriceResponse> {
-    // Create an owned Vec<u8> for the key
-    let key: Vec<u8>;
+pub fn get_token_price(deps: Deps, mint: String) -> Result<TokenPriceResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let oracle = load_oracle(deps.storage, &denom)?;
     
-    if mint.starts_with("factory/") {
-        // For factory denoms, use the string bytes directly
-        key = mint.as_bytes().to_vec();
-    } else {
-        // For regular addresses, validate and convert to bytes
-        let mint_addr = deps.api.addr_validate(&mint)?;
-        key = mint_addr.as_bytes().to_vec();
-    }
-    
-    let oracle = VIEWERSHIP_ORACLES.load(deps.storage, &key)?;
-    
-    // In a real implementation, you would query the CW20 token contract
-    // to get the actual supply, but here we'll use a simplified approach
-    let price = calculate_price(&oracle, Uint128::zero())?;
+    let supply = TOKEN_SUPPLY.may_load(deps.storage, denom.key())?.unwrap_or_default();
+    let price = calculate_price(&oracle, supply)?;
     
     Ok(TokenPriceResponse { price })
-}
\ No newline at end of file
+}
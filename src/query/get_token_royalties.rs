@@ -0,0 +1,16 @@
+use cosmwasm_std::Deps;
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::RoyaltyTotalResponse,
+    state::{load_token_metadata, TOKEN_ROYALTIES}
+};
+
+pub fn get_token_royalties(deps: Deps, mint: String) -> Result<RoyaltyTotalResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    load_token_metadata(deps.storage, &denom)?;
+    
+    let total = TOKEN_ROYALTIES.may_load(deps.storage, denom.key())?.unwrap_or_default();
+    
+    Ok(RoyaltyTotalResponse { total })
+}
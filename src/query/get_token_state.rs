@@ -0,0 +1,34 @@
+use cosmwasm_std::{Deps, Env};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::TokenStateResponse,
+    state::{load_oracle, load_token_status, load_vault, TokenStatus, LAUNCH_CONFIGS, TOKEN_SUPPLY}
+};
+use super::{get_token_metadata, get_token_price};
+
+pub fn get_token_state(deps: Deps, env: Env, mint: String) -> Result<TokenStateResponse, ContractError> {
+    let metadata = get_token_metadata(deps, mint.clone())?;
+    let price = get_token_price(deps, mint.clone())?.price;
+    
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let oracle = load_oracle(deps.storage, &denom)?;
+    let vault = load_vault(deps.storage, &denom)?;
+    let circulating_supply = TOKEN_SUPPLY.may_load(deps.storage, denom.key())?.unwrap_or_default();
+    
+    let launch = LAUNCH_CONFIGS.may_load(deps.storage, denom.key())?;
+    let status = load_token_status(deps.storage, &denom)?.at(launch.as_ref(), env.block.time.seconds());
+    
+    // A token graduates once its vault has collected enough for liquidity
+    let graduated = matches!(status, TokenStatus::Graduating | TokenStatus::Graduated);
+    
+    Ok(TokenStateResponse {
+        metadata,
+        oracle,
+        vault,
+        circulating_supply,
+        graduated,
+        status,
+        price,
+    })
+}
@@ -0,0 +1,17 @@
+use cosmwasm_std::{Deps, Env};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::TokenStatusResponse,
+    state::{load_token_status, LAUNCH_CONFIGS}
+};
+
+pub fn get_token_status(deps: Deps, env: Env, mint: String) -> Result<TokenStatusResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let launch = LAUNCH_CONFIGS.may_load(deps.storage, denom.key())?;
+    
+    // A presale reads as live once the launch has started, before any purchase records it
+    let status = load_token_status(deps.storage, &denom)?.at(launch.as_ref(), env.block.time.seconds());
+    
+    Ok(TokenStatusResponse { status })
+}
@@ -0,0 +1,37 @@
+use cosmwasm_std::{Deps, Env};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::VestingResponse,
+    state::VESTING,
+    utils::vested_amount
+};
+
+pub fn get_vesting(
+    deps: Deps,
+    env: Env,
+    mint: String,
+    address: String,
+) -> Result<VestingResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let address = deps.api.addr_validate(&address)?;
+    
+    let schedule = VESTING
+        .may_load(deps.storage, (denom.key(), &address))?
+        .ok_or_else(|| ContractError::VestingNotFound {
+            mint: mint.clone(),
+            address: address.to_string(),
+        })?;
+    
+    let vested = vested_amount(&schedule, env.block.time.seconds());
+    
+    Ok(VestingResponse {
+        total: schedule.total,
+        claimed: schedule.claimed,
+        vested,
+        claimable: vested - schedule.claimed,
+        start_time: schedule.start_time,
+        cliff_time: schedule.cliff_time,
+        end_time: schedule.end_time,
+    })
+}
@@ -0,0 +1,20 @@
+use cosmwasm_std::{Deps, Order, StdResult};
+use crate::{
+    msg::{QuoteTokenResponse, QuoteTokensResponse},
+    state::QUOTE_TOKENS
+};
+
+pub fn list_quote_tokens(deps: Deps) -> StdResult<QuoteTokensResponse> {
+    let tokens = QUOTE_TOKENS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (token, inj_rate) = item?;
+            Ok(QuoteTokenResponse {
+                token: token.to_string(),
+                inj_rate,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    
+    Ok(QuoteTokensResponse { tokens })
+}
@@ -0,0 +1,53 @@
+use cosmwasm_std::{Deps, Order, StdResult};
+use cw_storage_plus::Bound;
+use crate::{
+    msg::{ListTokensResponse, TokenInfoResponse, TokenMetadataResponse},
+    state::{token_metadata, TokenMetadata, TOKEN_SUPPLY, TOKEN_VAULTS, VIEWERSHIP_ORACLES},
+    utils::calculate_price
+};
+
+// Pagination limits for listing queries
+pub(crate) const DEFAULT_LIMIT: u32 = 10;
+pub(crate) const MAX_LIMIT: u32 = 30;
+
+pub(crate) fn page_limit(limit: Option<u32>) -> usize {
+    limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize
+}
+
+// Combine a token's metadata with its oracle and vault state
+pub(crate) fn token_info(deps: Deps, metadata: TokenMetadata) -> StdResult<TokenInfoResponse> {
+    let key = metadata.mint.as_bytes();
+    
+    // Oracle and vault are created separately, so they may be missing
+    let oracle = VIEWERSHIP_ORACLES.may_load(deps.storage, key)?;
+    let vault = TOKEN_VAULTS.may_load(deps.storage, key)?;
+    let supply = TOKEN_SUPPLY.may_load(deps.storage, key)?.unwrap_or_default();
+    let price = oracle
+        .as_ref()
+        .map(|oracle| calculate_price(oracle, supply))
+        .transpose()?;
+    
+    Ok(TokenInfoResponse {
+        metadata: TokenMetadataResponse::from(metadata),
+        price,
+        oracle,
+        vault,
+    })
+}
+
+pub fn list_tokens(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ListTokensResponse> {
+    let limit = page_limit(limit);
+    let start = start_after.as_ref().map(|mint| Bound::exclusive(mint.as_bytes()));
+    
+    let tokens = token_metadata()
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| token_info(deps, item?.1))
+        .collect::<StdResult<Vec<_>>>()?;
+    
+    Ok(ListTokensResponse { tokens })
+}
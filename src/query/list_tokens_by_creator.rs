@@ -0,0 +1,29 @@
+use cosmwasm_std::{Deps, Order, StdResult};
+use cw_storage_plus::Bound;
+use crate::{
+    msg::ListTokensResponse,
+    state::token_metadata
+};
+use super::list_tokens::{page_limit, token_info};
+
+pub fn list_tokens_by_creator(
+    deps: Deps,
+    creator: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ListTokensResponse> {
+    let creator = deps.api.addr_validate(&creator)?;
+    let limit = page_limit(limit);
+    let start = start_after.map(|mint| Bound::exclusive(mint.into_bytes()));
+    
+    let tokens = token_metadata()
+        .idx
+        .creator
+        .prefix(creator)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| token_info(deps, item?.1))
+        .collect::<StdResult<Vec<_>>>()?;
+    
+    Ok(ListTokensResponse { tokens })
+}
@@ -3,8 +3,62 @@ mod get_platform_config;
 mod get_token_metadata;
 mod get_token_price;
 mod get_vault_balance;
+mod list_tokens;
+mod get_token_by_id;
+mod list_tokens_by_creator;
+mod search_tokens_by_name_prefix;
+mod simulate_purchase;
+mod simulate_purchase_exact_in;
+mod get_token_state;
+mod get_token_royalties;
+mod get_creator_royalties;
+mod get_revenue_split;
+mod get_claimable;
+mod get_referral_stats;
+mod list_quote_tokens;
+mod get_vesting;
+mod get_launch_config;
+mod get_presale_allocation;
+mod get_airdrop;
+mod get_airdrop_claim;
+mod get_reward_pool;
+mod get_holder_rewards;
+mod get_staking_pool;
+mod get_staked_position;
+mod get_metadata_history;
+mod verify_content;
+mod get_token_status;
+mod get_delisting;
+mod get_disputes;
 
 pub use get_platform_config::get_platform_config;
 pub use get_token_metadata::get_token_metadata;
 pub use get_token_price::get_token_price;
 pub use get_vault_balance::get_vault_balance;
+pub use list_tokens::list_tokens;
+pub use get_token_by_id::get_token_by_id;
+pub use list_tokens_by_creator::list_tokens_by_creator;
+pub use search_tokens_by_name_prefix::search_tokens_by_name_prefix;
+pub use simulate_purchase::simulate_purchase;
+pub use simulate_purchase_exact_in::simulate_purchase_exact_in;
+pub use get_token_state::get_token_state;
+pub use get_token_royalties::get_token_royalties;
+pub use get_creator_royalties::get_creator_royalties;
+pub use get_revenue_split::get_revenue_split;
+pub use get_claimable::get_claimable;
+pub use get_referral_stats::get_referral_stats;
+pub use list_quote_tokens::list_quote_tokens;
+pub use get_vesting::get_vesting;
+pub use get_launch_config::get_launch_config;
+pub use get_presale_allocation::get_presale_allocation;
+pub use get_airdrop::get_airdrop;
+pub use get_airdrop_claim::get_airdrop_claim;
+pub use get_reward_pool::get_reward_pool;
+pub use get_holder_rewards::get_holder_rewards;
+pub use get_staking_pool::get_staking_pool;
+pub use get_staked_position::get_staked_position;
+pub use get_metadata_history::get_metadata_history;
+pub use verify_content::verify_content;
+pub use get_token_status::get_token_status;
+pub use get_delisting::get_delisting;
+pub use get_disputes::get_disputes;
@@ -0,0 +1,42 @@
+use cosmwasm_std::{Deps, Order, StdResult};
+use cw_storage_plus::Bound;
+use crate::{
+    msg::ListTokensResponse,
+    state::{name_index_key, token_metadata},
+    utils::normalize_name
+};
+use super::list_tokens::{page_limit, token_info};
+
+pub fn search_tokens_by_name_prefix(
+    deps: Deps,
+    prefix: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ListTokensResponse> {
+    let prefix = normalize_name(&prefix);
+    let limit = page_limit(limit);
+    let tokens = token_metadata();
+    
+    // Resume after the given mint's position in the name index, otherwise at the prefix itself
+    let start = match start_after {
+        Some(mint) => {
+            let metadata = tokens.load(deps.storage, mint.as_bytes())?;
+            Bound::exclusive(name_index_key(&metadata))
+        },
+        None => Bound::inclusive(prefix.clone()),
+    };
+    
+    let tokens = tokens
+        .idx
+        .name
+        .range(deps.storage, Some(start), None, Order::Ascending)
+        .take_while(|item| match item {
+            Ok((_, metadata)) => normalize_name(&metadata.name).starts_with(&prefix),
+            Err(_) => true,
+        })
+        .take(limit)
+        .map(|item| token_info(deps, item?.1))
+        .collect::<StdResult<Vec<_>>>()?;
+    
+    Ok(ListTokensResponse { tokens })
+}
@@ -0,0 +1,86 @@
+use cosmwasm_std::{Deps, Env, StdResult, Uint128};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::SimulatePurchaseResponse,
+    state::{
+        current_price, load_oracle, load_token_metadata, load_vault, TokenMetadata, TokenStatus, TokenVault,
+        LAUNCH_CONFIGS, TOKEN_SUPPLY
+    },
+    utils::{calculate_price, quote_at_price, PurchaseQuote}
+};
+
+// What a purchase made now would see: the token's status and the price after any presale discount
+pub(crate) struct SimulationTerms {
+    pub metadata: TokenMetadata,
+    pub vault: TokenVault,
+    pub supply: Uint128,
+    pub status: TokenStatus,
+    pub presale: bool,
+    pub price_per_token: Uint128,
+}
+
+// Load the terms a purchase would get, failing where the purchase would; presale allowlists are not checked
+pub(crate) fn simulation_terms(
+    deps: Deps,
+    env: &Env,
+    denom: &FactoryDenom,
+) -> Result<SimulationTerms, ContractError> {
+    let metadata = load_token_metadata(deps.storage, denom)?;
+    let oracle = load_oracle(deps.storage, denom)?;
+    let vault = load_vault(deps.storage, denom)?;
+    let supply = TOKEN_SUPPLY.may_load(deps.storage, denom.key())?.unwrap_or_default();
+    let launch = LAUNCH_CONFIGS.may_load(deps.storage, denom.key())?;
+    
+    let base_price = calculate_price(&oracle, supply)?;
+    let (status, sale, price_per_token) =
+        current_price(deps.storage, denom, launch.as_ref(), base_price, env.block.time.seconds())?;
+    
+    Ok(SimulationTerms {
+        metadata,
+        vault,
+        supply,
+        status,
+        presale: sale.is_some(),
+        price_per_token,
+    })
+}
+
+// Turn a quote into the response, including supply and threshold effects
+pub(crate) fn simulation_response(
+    terms: &SimulationTerms,
+    quote: PurchaseQuote,
+) -> StdResult<SimulatePurchaseResponse> {
+    let vault = &terms.vault;
+    let resulting_supply = terms.supply.checked_add(quote.amount_tokens)?;
+    let resulting_collected = vault.total_collected.checked_add(quote.vault_amount)?;
+    let crosses_liquidity_threshold = vault.total_collected < vault.liquidity_threshold
+        && resulting_collected >= vault.liquidity_threshold;
+    
+    Ok(SimulatePurchaseResponse {
+        amount_tokens: quote.amount_tokens,
+        price_per_token: quote.price_per_token,
+        total_cost: quote.total_cost,
+        platform_fee: quote.platform_fee,
+        royalty: quote.royalty,
+        vault_amount: quote.vault_amount,
+        resulting_supply,
+        crosses_liquidity_threshold,
+        status: terms.status,
+        presale: terms.presale,
+    })
+}
+
+pub fn simulate_purchase(
+    deps: Deps,
+    env: Env,
+    mint: String,
+    amount_tokens: Uint128,
+) -> Result<SimulatePurchaseResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let terms = simulation_terms(deps, &env, &denom)?;
+    
+    let quote = quote_at_price(terms.price_per_token, amount_tokens, terms.metadata.royalty_bps)?;
+    
+    Ok(simulation_response(&terms, quote)?)
+}
@@ -0,0 +1,24 @@
+use cosmwasm_std::{Deps, Env, StdError, Uint128};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::SimulatePurchaseResponse,
+    utils::quote_at_price
+};
+use super::simulate_purchase::{simulation_response, simulation_terms};
+
+pub fn simulate_purchase_exact_in(
+    deps: Deps,
+    env: Env,
+    mint: String,
+    funds: Uint128,
+) -> Result<SimulatePurchaseResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let terms = simulation_terms(deps, &env, &denom)?;
+    
+    // Spend as much of `funds` as buys whole tokens; the rest is left over
+    let amount_tokens = funds.checked_div(terms.price_per_token).map_err(StdError::from)?;
+    let quote = quote_at_price(terms.price_per_token, amount_tokens, terms.metadata.royalty_bps)?;
+    
+    Ok(simulation_response(&terms, quote)?)
+}
@@ -0,0 +1,24 @@
+use cosmwasm_std::{Deps, HexBinary};
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    msg::VerifyContentResponse,
+    state::load_token_metadata
+};
+
+pub fn verify_content(
+    deps: Deps,
+    mint: String,
+    hash: HexBinary,
+) -> Result<VerifyContentResponse, ContractError> {
+    let denom = FactoryDenom::parse(deps.api, &mint)?;
+    let metadata = load_token_metadata(deps.storage, &denom)?;
+    
+    // Tokens launched without a hash match nothing
+    let matches = metadata.content_hash.as_ref() == Some(&hash);
+    
+    Ok(VerifyContentResponse {
+        matches,
+        content_hash: metadata.content_hash,
+    })
+}
@@ -1,7 +1,15 @@
+use std::fmt;
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use cosmwasm_std::{Addr, Uint128};
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Addr, Decimal, Decimal256, Empty, Event, HexBinary, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex, UniqueIndex};
+
+use crate::{
+    denom::FactoryDenom,
+    error::ContractError,
+    utils::{accrued_reward, normalize_name, DEFAULT_UNBONDING_PERIOD}
+};
 
 // Platform configuration
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -9,15 +17,81 @@ pub struct Config {
     pub platform_wallet: Addr,
     pub oracle_authority: Addr,
     pub authority: Addr,
+    // Settings added after the first release default when reading an older config
+    #[serde(default)]
+    pub referral_share_bps: u16, // Portion of the platform fee paid to referrers
+    #[serde(default)]
+    pub holder_reward_bps: u16, // Portion of INJ platform fees paid to the song's holders
+    #[serde(default)]
+    pub staker_reward_bps: u16, // Portion of INJ platform fees paid to the song's stakers
+    #[serde(default = "default_unbonding_period")]
+    pub unbonding_period: u64, // Seconds before unstaked tokens can be withdrawn
+    #[serde(default)]
+    pub master_nft_contract: Option<Addr>, // CW721 collection the contract mints master NFTs in
+    #[serde(default)]
+    pub moderator: Option<Addr>, // Places and resolves dispute holds
+    #[serde(default)]
+    pub launch_fee: Uint128, // INJ charged by LaunchToken
+}
+
+fn default_unbonding_period() -> u64 {
+    DEFAULT_UNBONDING_PERIOD
+}
+
+// Referral earnings of one referrer
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct ReferralStats {
+    pub total_earned: Uint128,
+    pub referred_purchases: u64,
 }
 
 // Token metadata - modified to use String for mint instead of Addr
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 pub struct TokenMetadata {
     pub name: String,
-    pub music_uri: String,
+    pub music_uri: String, // Audio file URI
+    #[serde(default)]
+    pub content_hash: Option<HexBinary>, // sha256 of the audio file
     pub id: u64,
     pub mint: String, // Changed from Addr to String for Token Factory denom
+    // Artist receiving royalties, defaults to the denom creator; empty in metadata saved before
+    // creators were recorded until migrate() fills it in
+    #[serde(default = "unset_addr")]
+    pub creator: Addr,
+    #[serde(default)]
+    pub royalty_bps: u16,
+    // CW721 token whose owner holds the creator rights, if one was minted
+    #[serde(default)]
+    pub master_nft: Option<MasterNft>,
+    #[serde(default)]
+    pub details: SongDetails,
+    // Frozen metadata can no longer be updated
+    #[serde(default)]
+    pub frozen: bool,
+}
+
+fn unset_addr() -> Addr {
+    Addr::unchecked("")
+}
+
+// Optional descriptive metadata of a song, validated in crate::metadata
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct SongDetails {
+    pub artist: Option<String>,
+    pub symbol: Option<String>,
+    pub description: Option<String>,
+    pub cover_uri: Option<String>,
+    pub isrc: Option<String>,
+    pub genre: Option<String>,
+    pub duration_seconds: Option<u64>,
+    pub links: Option<Vec<String>>,
+}
+
+// Master rights NFT of a song
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MasterNft {
+    pub contract: Addr,
+    pub token_id: String,
 }
 
 // Price parameters
@@ -43,12 +117,701 @@ pub struct TokenVault {
     pub vault_account: Option<Addr>,
     pub inj_vault_wallet: Addr, 
     pub liquidity_threshold: Uint128,
-    pub total_collected: Uint128,
+    pub total_collected: Uint128, // INJ value of all payments, CW20 ones priced in INJ
     pub dex_pool: Option<Addr>,
+    // INJ actually paid in; unset on vaults from before CW20 payments, which only hold INJ
+    #[serde(default)]
+    pub native_collected: Option<Uint128>,
+}
+
+impl TokenVault {
+    // INJ the vault wallet holds from sales, which is what can back redemptions
+    pub fn inj_collected(&self) -> Uint128 {
+        self.native_collected.unwrap_or(self.total_collected)
+    }
+}
+
+// Where a token is in its lifecycle
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenStatus {
+    Draft, // Metadata registered, oracle or vault not set up yet
+    Presale, // Open to allowlisted presale buyers until the launch starts
+    Live, // Open to all buyers
+    Graduating, // Vault reached its liquidity threshold; trading halted while liquidity moves to a DEX
+    Graduated, // Trading continues on the DEX pool
+    Delisted, // Taken down; no further trading
+    // Frozen by a moderator pending a dispute: no trading, minting, graduation, view updates,
+    // staking, unstaking or reward claims for the song, and no release of its tokens from vesting,
+    // airdrops or unbonding. Only the moderator's release, or a delisting, ends it.
+    OnHold,
+}
+
+impl TokenStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenStatus::Draft => "draft",
+            TokenStatus::Presale => "presale",
+            TokenStatus::Live => "live",
+            TokenStatus::Graduating => "graduating",
+            TokenStatus::Graduated => "graduated",
+            TokenStatus::Delisted => "delisted",
+            TokenStatus::OnHold => "on_hold",
+        }
+    }
+    
+    // Status a token opens with once it is set up: presale if one is still ahead, else live
+    pub fn opening(launch: Option<&LaunchConfig>, now: u64) -> TokenStatus {
+        match launch {
+            Some(launch) if launch.presale.is_some() && now < launch.start_time => TokenStatus::Presale,
+            _ => TokenStatus::Live,
+        }
+    }
+    
+    // A presale ends by itself at the launch start time
+    pub fn at(self, launch: Option<&LaunchConfig>, now: u64) -> TokenStatus {
+        match self {
+            TokenStatus::Presale => TokenStatus::opening(launch, now),
+            status => status,
+        }
+    }
+    
+    pub fn is_tradable(&self) -> bool {
+        matches!(self, TokenStatus::Presale | TokenStatus::Live)
+    }
+    
+    pub fn can_transition_to(&self, next: TokenStatus) -> bool {
+        use TokenStatus::*;
+    
+        matches!(
+            (self, next),
+            (Draft, Presale)
+                | (Draft, Live)
+                | (Presale, Live)
+                | (Live, Presale)
+                | (Presale, Graduating)
+                | (Live, Graduating)
+                | (Graduating, Graduated)
+                | (Draft | Presale | Live | Graduating | Graduated, OnHold)
+                | (Draft | Presale | Live | Graduating | Graduated | OnHold, Delisted)
+        )
+    }
+}
+
+impl fmt::Display for TokenStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// Redemption terms of a delisted token, fixed when it was delisted
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Delisting {
+    pub reason_uri: Option<String>,
+    pub delisted_at: u64,
+    pub reserve: Uint128, // INJ set aside for redemptions
+    pub supply: Uint128, // Issued supply, pre-mine and airdrop deposits when delisted
+    pub rate: Decimal256, // INJ paid per token redeemed
+    pub redeemed: Uint128,
+    pub paid_out: Uint128,
+}
+
+// How a dispute hold ended
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HoldOutcome {
+    Release,
+    Delist,
+}
+
+impl HoldOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HoldOutcome::Release => "release",
+            HoldOutcome::Delist => "delist",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DisputeResolution {
+    pub outcome: HoldOutcome,
+    pub resolved_by: Addr,
+    pub resolved_at: u64,
+    pub resolution_uri: Option<String>,
+}
+
+// Moderation hold placed on a token pending a dispute
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Dispute {
+    pub seq: u64,
+    pub moderator: Addr,
+    pub reason_uri: String,
+    pub placed_at: u64,
+    pub previous_status: TokenStatus, // Restored when the hold is released
+    pub resolution: Option<DisputeResolution>,
+}
+
+// Share of a token's royalty owed to one rights holder
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SplitRecipient {
+    pub address: Addr,
+    pub weight_bps: u16,
+}
+
+// Linear vesting with a cliff for tokens the contract holds for a beneficiary
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct VestingSchedule {
+    pub total: Uint128,
+    pub claimed: Uint128,
+    pub start_time: u64,
+    pub cliff_time: u64,
+    pub end_time: u64,
+}
+
+// Per-token launch protections against sniping
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct LaunchConfig {
+    pub start_time: u64,
+    // Until this time only allowlisted addresses can buy
+    pub allowlist_end_time: Option<u64>,
+    // Until this time purchases per address are capped by max_per_address
+    pub protection_end_time: u64,
+    pub max_per_address: Option<Uint128>,
+    pub max_per_tx: Option<Uint128>,
+    pub presale: Option<PresaleConfig>,
+}
+
+// Discounted sale for a Merkle allowlist, running from start_time until the launch starts
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PresaleConfig {
+    // Root of a tree whose leaves are sha256(address)
+    pub merkle_root: HexBinary,
+    pub start_time: u64,
+    pub discount_bps: u16,
+    pub max_per_address: Uint128,
+}
+
+// Listener airdrop of a song denom, deposited by its creator
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Airdrop {
+    // Root of a tree whose leaves are sha256("{address}{amount}")
+    pub merkle_root: HexBinary,
+    pub total_amount: Uint128,
+    pub claimed_amount: Uint128,
+    pub expiry: u64,
+    // Set once the creator has taken back the unclaimed remainder
+    pub reclaimed: bool,
+}
+
+// Holder rewards of a song, in INJ. Fees are shared among the holders at the time they are
+// paid and become claimable at the next view update.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct RewardPool {
+    // Fees collected since the last view update, released to holders on the next one
+    pub pending: Uint128,
+    // Part of `pending` already shared among holders; the rest arrived while nobody held
+    #[serde(default)]
+    pub allocated: Uint128,
+    // Cumulative INJ shared out per token held
+    pub reward_per_token: Decimal256,
+    // Part of `reward_per_token` shared out since the last view update
+    #[serde(default)]
+    pub unreleased_per_token: Decimal256,
+    pub total_holdings: Uint128,
+}
+
+impl RewardPool {
+    // Cumulative INJ released per token held
+    pub fn released_per_token(&self) -> Decimal256 {
+        self.reward_per_token - self.unreleased_per_token
+    }
+    
+    // Share fees nobody held tokens for among the current holders
+    pub fn allocate(&mut self) {
+        let unallocated = self.pending - self.allocated;
+        if !unallocated.is_zero() && !self.total_holdings.is_zero() {
+            let per_token = Decimal256::from_ratio(unallocated, self.total_holdings);
+            self.reward_per_token += per_token;
+            self.unreleased_per_token += per_token;
+            self.allocated = self.pending;
+        }
+    }
+    
+    // Make every shared fee claimable, returning the amount released
+    pub fn release(&mut self) -> Uint128 {
+        self.allocate();
+        let released = self.allocated;
+        self.pending -= released;
+        self.allocated = Uint128::zero();
+        self.unreleased_per_token = Decimal256::zero();
+        
+        released
+    }
+}
+
+// A holder's position in a song's reward pool
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct HolderRewards {
+    // Tokens received from the contract (purchases, mints, airdrops, vesting, unstaking) less those
+    // handed back to it (stakes, redemptions). Bank transfers between wallets are not visible to the
+    // contract, so a holder who sends tokens away keeps earning on them until they come back.
+    pub holdings: Uint128,
+    pub reward_per_token_paid: Decimal256,
+    // Earned but not yet released by a view update
+    #[serde(default)]
+    pub pending: Uint128,
+    pub accrued: Uint128,
+}
+
+// Staked tokens of a song and the INJ rewards paid per staked token
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct StakingPool {
+    pub total_staked: Uint128,
+    pub total_unbonding: Uint128,
+    pub reward_per_token: Decimal256,
+}
+
+// A staker's position in a song's staking pool
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct StakerInfo {
+    pub staked: Uint128,
+    pub reward_per_token_paid: Decimal256,
+    pub accrued: Uint128,
+}
+
+// Unstaked tokens waiting out the unbonding period
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Unbonding {
+    pub amount: Uint128,
+    pub release_time: u64,
+}
+
+// One field of a metadata change, with its value before and after
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+// Audit trail entry for a token's metadata
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MetadataChange {
+    pub seq: u64,
+    pub action: String,
+    pub editor: Addr,
+    pub timestamp: u64,
+    pub changes: Vec<FieldChange>,
 }
 
 // Define storage - using denom strings as keys
 pub const CONFIG: Item<Config> = Item::new("config");
-pub const TOKEN_METADATA: Map<&[u8], TokenMetadata> = Map::new("token_metadata");
 pub const VIEWERSHIP_ORACLES: Map<&[u8], ViewershipOracle> = Map::new("viewership_oracles");
-pub const TOKEN_VAULTS: Map<&[u8], TokenVault> = Map::new("token_vaults");
\ No newline at end of file
+pub const TOKEN_VAULTS: Map<&[u8], TokenVault> = Map::new("token_vaults");
+// Tokens issued through the contract per denom
+pub const TOKEN_SUPPLY: Map<&[u8], Uint128> = Map::new("token_supply");
+// Lifecycle status per denom; migrate() fills it in for tokens set up before statuses existed
+pub const TOKEN_STATUS: Map<&[u8], TokenStatus> = Map::new("token_status");
+// Token metadata lives in an IndexedMap, see token_metadata()
+// Royalties paid out per denom and per recipient (the artist, master NFT owner or split recipient)
+pub const TOKEN_ROYALTIES: Map<&[u8], Uint128> = Map::new("token_royalties");
+pub const CREATOR_ROYALTIES: Map<&Addr, Uint128> = Map::new("creator_royalties");
+// Revenue split of the royalty per denom
+pub const REVENUE_SPLITS: Map<&[u8], Vec<SplitRecipient>> = Map::new("revenue_splits");
+// Fees and royalties accrued per (recipient, payment denom), and their sum per payment denom
+pub const CLAIMABLE: Map<(&Addr, &str), Uint128> = Map::new("claimable");
+pub const TOTAL_CLAIMABLE: Map<&str, Uint128> = Map::new("total_claimable");
+// Referral earnings per referrer
+pub const REFERRAL_STATS: Map<&Addr, ReferralStats> = Map::new("referral_stats");
+// Whitelisted CW20 quote tokens and their rate in quote token units per INJ unit
+pub const QUOTE_TOKENS: Map<&Addr, Decimal> = Map::new("quote_tokens");
+// Creator pre-mine vesting per (denom, beneficiary)
+pub const VESTING: Map<(&[u8], &Addr), VestingSchedule> = Map::new("vesting");
+// Launch protections per denom, the launch allowlist and amounts bought while protected
+pub const LAUNCH_CONFIGS: Map<&[u8], LaunchConfig> = Map::new("launch_configs");
+pub const LAUNCH_ALLOWLIST: Map<(&[u8], &Addr), Empty> = Map::new("launch_allowlist");
+pub const LAUNCH_PURCHASES: Map<(&[u8], &Addr), Uint128> = Map::new("launch_purchases");
+pub const PRESALE_PURCHASES: Map<(&[u8], &Addr), Uint128> = Map::new("presale_purchases");
+// Holder reward pools per denom, holder positions per (denom, holder), and INJ owed to holders
+pub const REWARD_POOLS: Map<&[u8], RewardPool> = Map::new("reward_pools");
+pub const HOLDER_REWARDS: Map<(&[u8], &Addr), HolderRewards> = Map::new("holder_rewards");
+pub const HOLDER_REWARDS_OUTSTANDING: Item<Uint128> = Item::new("holder_rewards_outstanding");
+// Staking pools per denom, staker positions and unbonding queues per (denom, staker),
+// and INJ owed to stakers
+pub const STAKING_POOLS: Map<&[u8], StakingPool> = Map::new("staking_pools");
+pub const STAKERS: Map<(&[u8], &Addr), StakerInfo> = Map::new("stakers");
+pub const UNBONDING: Map<(&[u8], &Addr), Vec<Unbonding>> = Map::new("unbonding");
+pub const STAKING_REWARDS_OUTSTANDING: Item<Uint128> = Item::new("staking_rewards_outstanding");
+// Redemption terms per delisted denom, and INJ reserved for redemptions not yet paid out
+pub const DELISTINGS: Map<&[u8], Delisting> = Map::new("delistings");
+pub const REDEMPTION_RESERVES_OUTSTANDING: Item<Uint128> = Item::new("redemption_reserves_outstanding");
+// Dispute holds per (denom, sequence number), the latest possibly still open
+pub const DISPUTES: Map<(&[u8], u64), Dispute> = Map::new("disputes");
+// Metadata audit trail per (denom, sequence number)
+pub const METADATA_HISTORY: Map<(&[u8], u64), MetadataChange> = Map::new("metadata_history");
+// Listener airdrops per denom and amounts claimed per (denom, claimant)
+pub const AIRDROPS: Map<&[u8], Airdrop> = Map::new("airdrops");
+pub const AIRDROP_CLAIMS: Map<(&[u8], &Addr), Uint128> = Map::new("airdrop_claims");
+
+// Secondary indexes over token metadata
+pub struct TokenMetadataIndexes<'a> {
+    // Unique song id
+    pub id: UniqueIndex<'a, u64, TokenMetadata, Vec<u8>>,
+    // All tokens launched by a creator
+    pub creator: MultiIndex<'a, Addr, TokenMetadata, Vec<u8>>,
+    // Normalized name followed by the mint, so names can be prefix-scanned
+    pub name: UniqueIndex<'a, String, TokenMetadata, Vec<u8>>,
+}
+
+impl<'a> IndexList<TokenMetadata> for TokenMetadataIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<TokenMetadata>> + '_> {
+        let v: Vec<&dyn Index<TokenMetadata>> = vec![&self.id, &self.creator, &self.name];
+        Box::new(v.into_iter())
+    }
+}
+
+pub fn name_index_key(metadata: &TokenMetadata) -> String {
+    format!("{}\0{}", normalize_name(&metadata.name), metadata.mint)
+}
+
+pub fn token_metadata<'a>() -> IndexedMap<'a, &'a [u8], TokenMetadata, TokenMetadataIndexes<'a>> {
+    let indexes = TokenMetadataIndexes {
+        id: UniqueIndex::new(|metadata| metadata.id, "token_metadata__id"),
+        creator: MultiIndex::new(
+            |_pk, metadata| metadata.creator.clone(),
+            "token_metadata",
+            "token_metadata__creator",
+        ),
+        name: UniqueIndex::new(name_index_key, "token_metadata__name"),
+    };
+    IndexedMap::new("token_metadata", indexes)
+}
+
+// Load helpers that tell an unknown token apart from a partially set up one
+pub fn load_token_metadata(
+    storage: &dyn Storage,
+    denom: &FactoryDenom,
+) -> Result<TokenMetadata, ContractError> {
+    token_metadata()
+        .may_load(storage, denom.key())?
+        .ok_or_else(|| ContractError::TokenNotFound { mint: denom.to_string() })
+}
+
+pub fn load_oracle(
+    storage: &dyn Storage,
+    denom: &FactoryDenom,
+) -> Result<ViewershipOracle, ContractError> {
+    match VIEWERSHIP_ORACLES.may_load(storage, denom.key())? {
+        Some(oracle) => Ok(oracle),
+        None => Err(missing_part(storage, denom, "oracle")),
+    }
+}
+
+pub fn load_vault(
+    storage: &dyn Storage,
+    denom: &FactoryDenom,
+) -> Result<TokenVault, ContractError> {
+    match TOKEN_VAULTS.may_load(storage, denom.key())? {
+        Some(vault) => Ok(vault),
+        None => Err(missing_part(storage, denom, "vault")),
+    }
+}
+
+fn missing_part(storage: &dyn Storage, denom: &FactoryDenom, missing: &str) -> ContractError {
+    if token_metadata().has(storage, denom.key()) {
+        ContractError::TokenNotInitialized {
+            mint: denom.to_string(),
+            missing: missing.to_string(),
+        }
+    } else {
+        ContractError::TokenNotFound { mint: denom.to_string() }
+    }
+}
+
+// Stored lifecycle status; migrate() gives tokens set up before statuses existed one
+pub fn load_token_status(
+    storage: &dyn Storage,
+    denom: &FactoryDenom,
+) -> Result<TokenStatus, ContractError> {
+    TOKEN_STATUS
+        .may_load(storage, denom.key())?
+        .ok_or_else(|| ContractError::TokenNotFound { mint: denom.to_string() })
+}
+
+// Reject releases of a song's tokens while it is held for a dispute
+pub fn assert_not_on_hold(storage: &dyn Storage, denom: &FactoryDenom) -> Result<(), ContractError> {
+    let status = load_token_status(storage, denom)?;
+    if status == TokenStatus::OnHold {
+        return Err(ContractError::InvalidTokenStatus { status });
+    }
+    
+    Ok(())
+}
+
+// Status and INJ price a buyer gets at `now`, with the presale the price comes from;
+// only presale and live tokens trade, and before the launch only a running presale sells
+pub fn current_price<'a>(
+    storage: &dyn Storage,
+    denom: &FactoryDenom,
+    launch: Option<&'a LaunchConfig>,
+    base_price: Uint128,
+    now: u64,
+) -> Result<(TokenStatus, Option<&'a PresaleConfig>, Uint128), ContractError> {
+    let status = load_token_status(storage, denom)?.at(launch, now);
+    if !status.is_tradable() {
+        return Err(ContractError::TokenNotTradable { mint: denom.to_string(), status });
+    }
+    
+    match launch {
+        Some(launch) if now < launch.start_time => {
+            let sale = launch
+                .presale
+                .as_ref()
+                .filter(|sale| now >= sale.start_time)
+                .ok_or(ContractError::LaunchNotStarted { start_time: launch.start_time })?;
+            let price = base_price.multiply_ratio(10_000 - sale.discount_bps as u128, 10_000u128);
+            
+            Ok((status, Some(sale), price))
+        }
+        _ => Ok((status, None, base_price)),
+    }
+}
+
+// Move a token to `next` if its lifecycle allows it, returning an event for an actual change
+pub fn set_token_status(
+    storage: &mut dyn Storage,
+    denom: &FactoryDenom,
+    next: TokenStatus,
+) -> Result<Option<Event>, ContractError> {
+    let current = load_token_status(storage, denom)?;
+    if current == next {
+        return Ok(None);
+    }
+    if !current.can_transition_to(next) {
+        return Err(ContractError::InvalidStatusTransition { from: current, to: next });
+    }
+    
+    TOKEN_STATUS.save(storage, denom.key(), &next)?;
+    
+    Ok(Some(status_event(denom, current, next)))
+}
+
+// Lift a hold, returning the token to the status it had; set_token_status never leaves OnHold
+// other than for Delisted, so a release cannot happen by any other route
+pub fn release_hold(
+    storage: &mut dyn Storage,
+    denom: &FactoryDenom,
+    status: TokenStatus,
+) -> Result<Event, ContractError> {
+    let current = load_token_status(storage, denom)?;
+    if current != TokenStatus::OnHold || matches!(status, TokenStatus::OnHold | TokenStatus::Delisted) {
+        return Err(ContractError::InvalidStatusTransition { from: current, to: status });
+    }
+    
+    TOKEN_STATUS.save(storage, denom.key(), &status)?;
+    
+    Ok(status_event(denom, current, status))
+}
+
+fn status_event(denom: &FactoryDenom, from: TokenStatus, to: TokenStatus) -> Event {
+    Event::new("token_status")
+        .add_attribute("mint", denom.as_str())
+        .add_attribute("from", from.as_str())
+        .add_attribute("to", to.as_str())
+}
+
+// Accrue an amount owed to `recipient`, to be pulled later with ClaimFees
+pub fn credit_claimable(
+    storage: &mut dyn Storage,
+    recipient: &Addr,
+    denom: &str,
+    amount: Uint128,
+) -> StdResult<()> {
+    if amount.is_zero() {
+        return Ok(());
+    }
+    
+    CLAIMABLE.update(storage, (recipient, denom), |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default().checked_add(amount)?)
+    })?;
+    TOTAL_CLAIMABLE.update(storage, denom, |total| -> StdResult<_> {
+        Ok(total.unwrap_or_default().checked_add(amount)?)
+    })?;
+    
+    Ok(())
+}
+
+// Amount of an asset (by ledger key) the contract holds on behalf of others
+pub fn reserved_amount(storage: &dyn Storage, ledger_key: &str) -> StdResult<Uint128> {
+    let claimable = TOTAL_CLAIMABLE.may_load(storage, ledger_key)?.unwrap_or_default();
+    
+    // Unreleased vesting allocations of a song denom
+    let vesting = VESTING
+        .prefix(ledger_key.as_bytes())
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, schedule)| schedule.total - schedule.claimed))
+        .sum::<StdResult<Uint128>>()?;
+    
+    // Airdrop allocations not yet claimed or reclaimed
+    let airdrop = AIRDROPS
+        .may_load(storage, ledger_key.as_bytes())?
+        .filter(|airdrop| !airdrop.reclaimed)
+        .map(|airdrop| airdrop.total_amount - airdrop.claimed_amount)
+        .unwrap_or_default();
+    
+    // INJ fees set aside for holders and stakers, and delisting reserves, not yet paid out
+    let rewards = match ledger_key {
+        "inj" => {
+            HOLDER_REWARDS_OUTSTANDING.may_load(storage)?.unwrap_or_default()
+                + STAKING_REWARDS_OUTSTANDING.may_load(storage)?.unwrap_or_default()
+                + REDEMPTION_RESERVES_OUTSTANDING.may_load(storage)?.unwrap_or_default()
+        },
+        _ => Uint128::zero(),
+    };
+    
+    // Staked and unbonding tokens of a song denom
+    let staked = STAKING_POOLS
+        .may_load(storage, ledger_key.as_bytes())?
+        .map(|pool| pool.total_staked + pool.total_unbonding)
+        .unwrap_or_default();
+    
+    // Delisted tokens handed in for redemption count as burned and never leave the contract
+    let redeemed = DELISTINGS
+        .may_load(storage, ledger_key.as_bytes())?
+        .map(|delisting| delisting.redeemed)
+        .unwrap_or_default();
+    
+    Ok(claimable + vesting + airdrop + rewards + staked + redeemed)
+}
+
+// Load a holder's position with rewards earned up to the pool's current index, split into the
+// part released by view updates and the part still pending
+pub fn load_holder_rewards(
+    storage: &dyn Storage,
+    mint_key: &[u8],
+    holder: &Addr,
+    pool: &RewardPool,
+) -> StdResult<HolderRewards> {
+    let mut rewards = HOLDER_REWARDS.may_load(storage, (mint_key, holder))?.unwrap_or_default();
+    let released_per_token = pool.released_per_token();
+    
+    // Everything pending was earned before the index reached reward_per_token_paid
+    if released_per_token >= rewards.reward_per_token_paid {
+        rewards.accrued = rewards.accrued.checked_add(rewards.pending)?;
+        rewards.pending = Uint128::zero();
+    }
+    
+    let paid = rewards.reward_per_token_paid.max(released_per_token);
+    let released = accrued_reward(rewards.holdings, paid - rewards.reward_per_token_paid)?;
+    let pending = accrued_reward(rewards.holdings, pool.reward_per_token - paid)?;
+    rewards.accrued = rewards.accrued.checked_add(released)?;
+    rewards.pending = rewards.pending.checked_add(pending)?;
+    rewards.reward_per_token_paid = pool.reward_per_token;
+    
+    Ok(rewards)
+}
+
+// Load a staker's position with rewards accrued up to the pool's current index
+pub fn load_staker(
+    storage: &dyn Storage,
+    mint_key: &[u8],
+    staker: &Addr,
+    pool: &StakingPool,
+) -> StdResult<StakerInfo> {
+    let mut info = STAKERS.may_load(storage, (mint_key, staker))?.unwrap_or_default();
+    let earned = accrued_reward(info.staked, pool.reward_per_token - info.reward_per_token_paid)?;
+    info.accrued = info.accrued.checked_add(earned)?;
+    info.reward_per_token_paid = pool.reward_per_token;
+    
+    Ok(info)
+}
+
+// Tokens a holder receives from the contract start earning holder rewards
+pub fn add_holdings(
+    storage: &mut dyn Storage,
+    mint_key: &[u8],
+    holder: &Addr,
+    amount: Uint128,
+) -> StdResult<()> {
+    let mut pool = REWARD_POOLS.may_load(storage, mint_key)?.unwrap_or_default();
+    let mut rewards = load_holder_rewards(storage, mint_key, holder, &pool)?;
+    rewards.holdings = rewards.holdings.checked_add(amount)?;
+    pool.total_holdings = pool.total_holdings.checked_add(amount)?;
+    
+    HOLDER_REWARDS.save(storage, (mint_key, holder), &rewards)?;
+    REWARD_POOLS.save(storage, mint_key, &pool)
+}
+
+// Tokens a holder hands to the contract stop earning; tokens it never recorded are ignored
+pub fn remove_holdings(
+    storage: &mut dyn Storage,
+    mint_key: &[u8],
+    holder: &Addr,
+    amount: Uint128,
+) -> StdResult<()> {
+    let mut pool = REWARD_POOLS.may_load(storage, mint_key)?.unwrap_or_default();
+    let mut rewards = load_holder_rewards(storage, mint_key, holder, &pool)?;
+    let removed = amount.min(rewards.holdings);
+    rewards.holdings -= removed;
+    pool.total_holdings -= removed;
+    
+    HOLDER_REWARDS.save(storage, (mint_key, holder), &rewards)?;
+    REWARD_POOLS.save(storage, mint_key, &pool)
+}
+
+// Append an entry to a token's metadata audit trail
+pub fn record_metadata_change(
+    storage: &mut dyn Storage,
+    mint_key: &[u8],
+    action: &str,
+    editor: &Addr,
+    timestamp: u64,
+    changes: Vec<FieldChange>,
+) -> StdResult<u64> {
+    let seq = METADATA_HISTORY
+        .prefix(mint_key)
+        .keys(storage, None, None, Order::Descending)
+        .next()
+        .transpose()?
+        .map_or(0, |last| last + 1);
+    
+    let change = MetadataChange {
+        seq,
+        action: action.to_string(),
+        editor: editor.clone(),
+        timestamp,
+        changes,
+    };
+    METADATA_HISTORY.save(storage, (mint_key, seq), &change)?;
+    
+    Ok(seq)
+}
+
+// A token's latest dispute, if it is still unresolved
+pub fn open_dispute(storage: &dyn Storage, mint_key: &[u8]) -> StdResult<Option<Dispute>> {
+    let last = DISPUTES
+        .prefix(mint_key)
+        .range(storage, None, None, Order::Descending)
+        .next()
+        .transpose()?;
+    
+    Ok(last.map(|(_, dispute)| dispute).filter(|dispute| dispute.resolution.is_none()))
+}
+
+// Close a token's open dispute, if it has one
+pub fn resolve_open_dispute(
+    storage: &mut dyn Storage,
+    mint_key: &[u8],
+    resolution: DisputeResolution,
+) -> StdResult<Option<Dispute>> {
+    let mut dispute = match open_dispute(storage, mint_key)? {
+        Some(dispute) => dispute,
+        None => return Ok(None),
+    };
+    
+    dispute.resolution = Some(resolution);
+    DISPUTES.save(storage, (mint_key, dispute.seq), &dispute)?;
+    
+    Ok(Some(dispute))
+}
@@ -0,0 +1,85 @@
+use cosmwasm_std::{Addr, Binary, CosmosMsg};
+
+// Injective token factory messages, protobuf-encoded by hand as stargate messages
+const CREATE_DENOM_TYPE_URL: &str = "/injective.tokenfactory.v1beta1.MsgCreateDenom";
+const SET_DENOM_METADATA_TYPE_URL: &str = "/injective.tokenfactory.v1beta1.MsgSetDenomMetadata";
+
+// Song tokens are indivisible: one base unit is one token
+pub const TOKEN_DECIMALS: u32 = 0;
+
+// Bank metadata of a song denom
+pub struct DenomMetadata {
+    pub description: String,
+    pub base: String,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub uri_hash: String,
+}
+
+fn put_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+// Length-delimited field (wire type 2); empty values are left out, as proto3 does
+fn put_bytes(buf: &mut Vec<u8>, field: u64, value: &[u8]) {
+    if value.is_empty() {
+        return;
+    }
+    put_varint(buf, field << 3 | 2);
+    put_varint(buf, value.len() as u64);
+    buf.extend_from_slice(value);
+}
+
+// Varint field (wire type 0); zero is the default and left out
+fn put_uint(buf: &mut Vec<u8>, field: u64, value: u64) {
+    if value == 0 {
+        return;
+    }
+    put_varint(buf, field << 3);
+    put_varint(buf, value);
+}
+
+fn stargate(type_url: &str, value: Vec<u8>) -> CosmosMsg {
+    CosmosMsg::Stargate {
+        type_url: type_url.to_string(),
+        value: Binary::from(value),
+    }
+}
+
+// Create factory/{sender}/{subdenom}, with the sender as its admin
+pub fn create_denom_msg(sender: &Addr, subdenom: &str, name: &str, symbol: &str) -> CosmosMsg {
+    let mut value = vec![];
+    put_bytes(&mut value, 1, sender.as_bytes());
+    put_bytes(&mut value, 2, subdenom.as_bytes());
+    put_bytes(&mut value, 3, name.as_bytes());
+    put_bytes(&mut value, 4, symbol.as_bytes());
+    put_uint(&mut value, 5, TOKEN_DECIMALS.into());
+    stargate(CREATE_DENOM_TYPE_URL, value)
+}
+
+// Set the bank metadata of a denom the sender administers
+pub fn set_denom_metadata_msg(sender: &Addr, metadata: &DenomMetadata) -> CosmosMsg {
+    // The base unit is the only unit, with exponent 0
+    let mut unit = vec![];
+    put_bytes(&mut unit, 1, metadata.base.as_bytes());
+    
+    let mut bank_metadata = vec![];
+    put_bytes(&mut bank_metadata, 1, metadata.description.as_bytes());
+    put_bytes(&mut bank_metadata, 2, &unit);
+    put_bytes(&mut bank_metadata, 3, metadata.base.as_bytes());
+    put_bytes(&mut bank_metadata, 4, metadata.base.as_bytes());
+    put_bytes(&mut bank_metadata, 5, metadata.name.as_bytes());
+    put_bytes(&mut bank_metadata, 6, metadata.symbol.as_bytes());
+    put_bytes(&mut bank_metadata, 7, metadata.uri.as_bytes());
+    put_bytes(&mut bank_metadata, 8, metadata.uri_hash.as_bytes());
+    
+    let mut value = vec![];
+    put_bytes(&mut value, 1, sender.as_bytes());
+    put_bytes(&mut value, 2, &bank_metadata);
+    stargate(SET_DENOM_METADATA_TYPE_URL, value)
+}
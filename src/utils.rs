@@ -1,5 +1,7 @@
-use cosmwasm_std::{StdResult, StdError, Uint128};
-use crate::state::ViewershipOracle;
+use cosmwasm_std::{Decimal, Decimal256, HexBinary, StdError, StdResult, Uint128, Uint256};
+use cosmwasm_std::Addr;
+use sha2::{Digest, Sha256};
+use crate::state::{SplitRecipient, VestingSchedule, ViewershipOracle};
 
 pub fn calculate_price(oracle: &ViewershipOracle, _supply: Uint128) -> StdResult<Uint128> {
     // Base price calculation using view count
@@ -20,3 +22,117 @@ pub fn get_inj_amount(funds: &[cosmwasm_std::Coin]) -> Uint128 {
         .unwrap_or(Uint128::zero())
 }
 
+// Case- and whitespace-insensitive form of a token name, used for name lookups
+pub fn normalize_name(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+// Platform fee charged on purchases, in parts per thousand (2.5%)
+pub const PLATFORM_FEE_PER_MILLE: u128 = 25;
+
+// Upper bound on the artist royalty, in basis points (10%)
+pub const MAX_ROYALTY_BPS: u16 = 1_000;
+
+// Breakdown of what a purchase of `amount_tokens` costs at the current price
+pub struct PurchaseQuote {
+    pub amount_tokens: Uint128,
+    pub price_per_token: Uint128,
+    pub total_cost: Uint128,
+    pub platform_fee: Uint128,
+    pub royalty: Uint128,
+    pub vault_amount: Uint128,
+}
+
+// Fee breakdown for `amount_tokens` at a given per-token price, in any payment asset
+pub fn quote_at_price(
+    price_per_token: Uint128,
+    amount_tokens: Uint128,
+    royalty_bps: u16,
+) -> StdResult<PurchaseQuote> {
+    let total_cost = price_per_token.checked_mul(amount_tokens)?;
+    let platform_fee = total_cost.multiply_ratio(PLATFORM_FEE_PER_MILLE, 1000u128);
+    let royalty = total_cost.multiply_ratio(royalty_bps, 10_000u128);
+    let vault_amount = total_cost.checked_sub(platform_fee + royalty)?;
+    
+    Ok(PurchaseQuote {
+        amount_tokens,
+        price_per_token,
+        total_cost,
+        platform_fee,
+        royalty,
+        vault_amount,
+    })
+}
+
+// Default wait before unstaked tokens can be withdrawn: seven days
+pub const DEFAULT_UNBONDING_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+// Maximum number of rights holders in a revenue split
+pub const MAX_SPLIT_RECIPIENTS: usize = 10;
+
+// Divide `amount` by split weights; rounding dust goes to the first recipient
+pub fn split_amount(amount: Uint128, recipients: &[SplitRecipient]) -> Vec<(Addr, Uint128)> {
+    let mut shares: Vec<(Addr, Uint128)> = recipients
+        .iter()
+        .map(|r| (r.address.clone(), amount.multiply_ratio(r.weight_bps, 10_000u128)))
+        .collect();
+    
+    let distributed: Uint128 = shares.iter().map(|(_, share)| *share).sum();
+    if let Some((_, first)) = shares.first_mut() {
+        *first += amount - distributed;
+    }
+    
+    shares
+}
+
+// Convert an INJ amount into a quote token using its rate (quote units per INJ unit)
+pub fn convert_inj_amount(amount: Uint128, inj_rate: Decimal) -> StdResult<Uint128> {
+    amount
+        .checked_multiply_ratio(inj_rate.atomics(), Decimal::one().atomics())
+        .map_err(|e| StdError::generic_err(e.to_string()))
+}
+
+// Amount of a schedule vested at `now` (seconds): nothing before the cliff, linear until the end
+pub fn vested_amount(schedule: &VestingSchedule, now: u64) -> Uint128 {
+    if now < schedule.cliff_time {
+        Uint128::zero()
+    } else if now >= schedule.end_time {
+        schedule.total
+    } else {
+        schedule.total.multiply_ratio(
+            now - schedule.start_time,
+            schedule.end_time - schedule.start_time,
+        )
+    }
+}
+
+// Rewards earned by `holdings` over a rise of `index_delta` in reward per token, rounded down
+pub fn accrued_reward(holdings: Uint128, index_delta: Decimal256) -> StdResult<Uint128> {
+    Ok((Uint256::from(holdings) * index_delta).try_into()?)
+}
+
+// Check a Merkle proof for `leaf` against `root`. Pairs are hashed in sorted order,
+// so proofs carry no left/right flags.
+pub fn verify_merkle_proof(root: &HexBinary, leaf: [u8; 32], proof: &[HexBinary]) -> bool {
+    let mut hash = leaf;
+    for sibling in proof {
+        let sibling: [u8; 32] = match sibling.as_slice().try_into() {
+            Ok(sibling) => sibling,
+            Err(_) => return false,
+        };
+        let (first, second) = if hash <= sibling { (hash, sibling) } else { (sibling, hash) };
+        hash = Sha256::new().chain_update(first).chain_update(second).finalize().into();
+    }
+    
+    root.as_slice() == hash
+}
+
+// Merkle leaf for an allowlisted address
+pub fn address_leaf(address: &Addr) -> [u8; 32] {
+    Sha256::digest(address.as_bytes()).into()
+}
+
+// Merkle leaf for an airdrop allocation
+pub fn airdrop_leaf(address: &Addr, amount: Uint128) -> [u8; 32] {
+    Sha256::digest(format!("{}{}", address, amount).as_bytes()).into()
+}
@@ -0,0 +1,133 @@
+mod common;
+
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{coins, Addr, HexBinary, Response, Uint128};
+use sha2::{Digest, Sha256};
+use hashmelody::{
+    error::ContractError,
+    msg::{AirdropClaimResponse, AirdropResponse, ExecuteMsg, QueryMsg},
+    utils::airdrop_leaf
+};
+use common::{at, bank_send, query_as, run, run_at, setup, TestDeps, MINT};
+
+// The airdrop can be claimed for this many seconds
+const EXPIRY: u64 = 1_000;
+
+fn leaf(address: &str, amount: u128) -> [u8; 32] {
+    airdrop_leaf(&Addr::unchecked(address), Uint128::new(amount))
+}
+
+// Root of the two-leaf tree allocating 300 tokens to bob and 200 to carl
+fn merkle_root() -> HexBinary {
+    let (a, b) = (leaf("bob", 300), leaf("carl", 200));
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let root: [u8; 32] = Sha256::new().chain_update(first).chain_update(second).finalize().into();
+    HexBinary::from(root.to_vec())
+}
+
+fn register_msg(merkle_root: HexBinary, total_amount: u128, expiry: u64) -> ExecuteMsg {
+    ExecuteMsg::RegisterAirdrop { mint: MINT.to_string(), merkle_root, total_amount: Uint128::new(total_amount), expiry }
+}
+
+// Alice deposits 600 tokens, 100 more than the tree allocates
+fn airdrop_deps() -> TestDeps {
+    let mut deps = setup();
+    let expiry = mock_env().block.time.seconds() + EXPIRY;
+    run(&mut deps, "alice", &coins(600, MINT), register_msg(merkle_root(), 600, expiry)).unwrap();
+    deps
+}
+
+fn claim_at(deps: &mut TestDeps, seconds: u64, claimer: &str, amount: u128, sibling: [u8; 32]) -> Result<Response, ContractError> {
+    let msg = ExecuteMsg::ClaimAirdrop {
+        mint: MINT.to_string(),
+        amount: Uint128::new(amount),
+        proof: vec![HexBinary::from(sibling.to_vec())],
+    };
+    run_at(deps, at(seconds), claimer, &[], msg)
+}
+
+fn reclaim_at(deps: &mut TestDeps, seconds: u64, sender: &str) -> Result<Response, ContractError> {
+    run_at(deps, at(seconds), sender, &[], ExecuteMsg::ReclaimAirdrop { mint: MINT.to_string() })
+}
+
+#[test]
+fn airdrop_registration_is_validated() {
+    let expiry = mock_env().block.time.seconds() + EXPIRY;
+    
+    let err = run(&mut setup(), "bob", &coins(600, MINT), register_msg(merkle_root(), 600, expiry)).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    
+    // A short root, nothing to drop, or an expiry that has already passed
+    let invalid = [
+        register_msg(HexBinary::from(vec![0u8; 31]), 600, expiry),
+        register_msg(merkle_root(), 0, expiry),
+        register_msg(merkle_root(), 600, mock_env().block.time.seconds()),
+    ];
+    for msg in invalid {
+        let err = run(&mut setup(), "alice", &coins(600, MINT), msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidAirdrop {}));
+    }
+    
+    let err = run(&mut setup(), "alice", &coins(599, MINT), register_msg(merkle_root(), 600, expiry)).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidDeposit { .. }));
+    
+    // One airdrop per token
+    let mut deps = airdrop_deps();
+    let err = run(&mut deps, "alice", &coins(600, MINT), register_msg(merkle_root(), 600, expiry)).unwrap_err();
+    assert!(matches!(err, ContractError::AirdropAlreadyExists {}));
+}
+
+#[test]
+fn listeners_claim_their_allocation_once() {
+    let mut deps = airdrop_deps();
+    
+    let res = claim_at(&mut deps, 10, "bob", 300, leaf("carl", 200)).unwrap();
+    assert_eq!(bank_send(&res), ("bob".to_string(), coins(300, MINT)));
+    let err = claim_at(&mut deps, 20, "bob", 300, leaf("carl", 200)).unwrap_err();
+    assert!(matches!(err, ContractError::AirdropAlreadyClaimed {}));
+    
+    claim_at(&mut deps, 20, "carl", 200, leaf("bob", 300)).unwrap();
+    
+    let claim: AirdropClaimResponse = query_as(&deps, QueryMsg::GetAirdropClaim { mint: MINT.to_string(), address: "bob".to_string() });
+    assert_eq!(claim.claimed, Uint128::new(300));
+    let airdrop: AirdropResponse = query_as(&deps, QueryMsg::GetAirdrop { mint: MINT.to_string() });
+    assert_eq!(airdrop.airdrop.claimed_amount, Uint128::new(500));
+}
+
+#[test]
+fn claims_must_match_the_tree() {
+    let mut deps = airdrop_deps();
+    
+    // A different amount, someone else's allocation, or an address not in the tree
+    let err = claim_at(&mut deps, 10, "bob", 301, leaf("carl", 200)).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidMerkleProof {}));
+    let err = claim_at(&mut deps, 10, "bob", 200, leaf("bob", 300)).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidMerkleProof {}));
+    let err = claim_at(&mut deps, 10, "dan", 300, leaf("carl", 200)).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidMerkleProof {}));
+}
+
+#[test]
+fn claims_close_at_expiry() {
+    let mut deps = airdrop_deps();
+    
+    let err = claim_at(&mut deps, EXPIRY, "bob", 300, leaf("carl", 200)).unwrap_err();
+    assert!(matches!(err, ContractError::AirdropExpired { .. }));
+}
+
+#[test]
+fn creator_reclaims_the_remainder_after_expiry() {
+    let mut deps = airdrop_deps();
+    claim_at(&mut deps, 10, "bob", 300, leaf("carl", 200)).unwrap();
+    
+    let err = reclaim_at(&mut deps, EXPIRY - 1, "alice").unwrap_err();
+    assert!(matches!(err, ContractError::AirdropNotExpired { .. }));
+    let err = reclaim_at(&mut deps, EXPIRY, "bob").unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    
+    // Carl's unclaimed 200 and the unallocated 100 go back to alice, once
+    let res = reclaim_at(&mut deps, EXPIRY, "alice").unwrap();
+    assert_eq!(bank_send(&res), ("alice".to_string(), coins(300, MINT)));
+    let err = reclaim_at(&mut deps, EXPIRY + 1, "alice").unwrap_err();
+    assert!(matches!(err, ContractError::NothingToClaim {}));
+}
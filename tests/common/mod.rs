@@ -0,0 +1,135 @@
+#![allow(dead_code)]
+
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{coins, from_json, BankMsg, Coin, CosmosMsg, Env, OwnedDeps, Response, Uint128};
+use serde::de::DeserializeOwned;
+use hashmelody::{
+    contract::{execute, instantiate, query},
+    error::ContractError,
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg}
+};
+
+pub type TestDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+// Alice's song; its creator registers it
+pub const MINT: &str = "factory/alice/song";
+
+// Price of one token with no views: k = 1 INJ
+pub const PRICE: u128 = 1_000_000_000;
+
+// Platform with a 50% referral share and 20% of fees each for holders and stakers;
+// the song pays a 5% royalty
+pub fn setup() -> TestDeps {
+    let mut deps = mock_dependencies();
+    let msg = InstantiateMsg {
+        platform_wallet: "platform".to_string(),
+        oracle_authority: "oracle".to_string(),
+        referral_share_bps: Some(5_000),
+        holder_reward_bps: Some(2_000),
+        staker_reward_bps: Some(2_000),
+        unbonding_period: Some(100),
+        launch_fee: None,
+        moderator: None,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("admin", &[]), msg).unwrap();
+    
+    register_song(&mut deps, "alice", "song", 1, "Song");
+    run(&mut deps, "alice", &[], ExecuteMsg::InitializeTokenOracle { mint: MINT.to_string() }).unwrap();
+    run(&mut deps, "alice", &[], ExecuteMsg::SetupVaultAccount { mint: MINT.to_string() }).unwrap();
+    
+    deps
+}
+
+// Block `seconds` after the mock block
+pub fn at(seconds: u64) -> Env {
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(seconds);
+    env
+}
+
+pub fn run(
+    deps: &mut TestDeps,
+    sender: &str,
+    funds: &[Coin],
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    execute(deps.as_mut(), mock_env(), mock_info(sender, funds), msg)
+}
+
+pub fn run_at(
+    deps: &mut TestDeps,
+    env: Env,
+    sender: &str,
+    funds: &[Coin],
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    execute(deps.as_mut(), env, mock_info(sender, funds), msg)
+}
+
+pub fn query_as<T: DeserializeOwned>(deps: &TestDeps, msg: QueryMsg) -> T {
+    from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap()
+}
+
+pub fn query_err(deps: &TestDeps, msg: QueryMsg) -> ContractError {
+    query(deps.as_ref(), mock_env(), msg).unwrap_err()
+}
+
+// Register metadata for `creator`'s song `subdenom`, returning its mint
+pub fn register_song(deps: &mut TestDeps, creator: &str, subdenom: &str, id: u64, name: &str) -> String {
+    let mint = format!("factory/{}/{}", creator, subdenom);
+    let msg = ExecuteMsg::InitializeTokenMetadata {
+        id,
+        name: name.to_string(),
+        music_uri: "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_string(),
+        content_hash: None,
+        mint: mint.clone(),
+        creator: None,
+        royalty_bps: 500,
+        vesting: None,
+        mint_master_nft: None,
+        details: None,
+    };
+    run(deps, creator, &[], msg).unwrap();
+    
+    mint
+}
+
+// Buy `amount` tokens of the song at the current price
+pub fn buy(
+    deps: &mut TestDeps,
+    buyer: &str,
+    amount: u128,
+    referrer: Option<&str>,
+) -> Result<Response, ContractError> {
+    let msg = ExecuteMsg::PurchaseToken {
+        mint: MINT.to_string(),
+        amount_tokens: Uint128::new(amount),
+        referrer: referrer.map(str::to_string),
+        proof: None,
+    };
+    run(deps, buyer, &coins(100 * PRICE, "inj"), msg)
+}
+
+pub fn attr<'a>(response: &'a Response, key: &str) -> &'a str {
+    &response.attributes.iter().find(|attr| attr.key == key).unwrap().value
+}
+
+// The single bank transfer a response makes
+pub fn bank_send(response: &Response) -> (String, Vec<Coin>) {
+    match &bank_sends(response)[..] {
+        [send] => send.clone(),
+        sends => panic!("expected one transfer, got {:?}", sends),
+    }
+}
+
+// Every transfer a response makes, in order
+pub fn bank_sends(response: &Response) -> Vec<(String, Vec<Coin>)> {
+    response
+        .messages
+        .iter()
+        .map(|message| match &message.msg {
+            CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => (to_address.clone(), amount.clone()),
+            other => panic!("unexpected message {:?}", other),
+        })
+        .collect()
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use cosmwasm_std::HexBinary;
+use sha2::{Digest, Sha256};
+use hashmelody::{
+    error::ContractError,
+    msg::{ExecuteMsg, QueryMsg, VerifyContentResponse}
+};
+use common::{query_as, query_err, run, setup, TestDeps};
+
+const MINT: &str = "factory/alice/hashed";
+
+fn sha256(data: &[u8]) -> HexBinary {
+    HexBinary::from(Sha256::digest(data).to_vec())
+}
+
+fn register(deps: &mut TestDeps, content_hash: Option<HexBinary>) -> Result<(), ContractError> {
+    let msg = ExecuteMsg::InitializeTokenMetadata {
+        id: 2,
+        name: "Hashed".to_string(),
+        music_uri: "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_string(),
+        content_hash,
+        mint: MINT.to_string(),
+        creator: None,
+        royalty_bps: 500,
+        vesting: None,
+        mint_master_nft: None,
+        details: None,
+    };
+    run(deps, "alice", &[], msg).map(|_| ())
+}
+
+fn verify(deps: &TestDeps, hash: HexBinary) -> VerifyContentResponse {
+    query_as(deps, QueryMsg::VerifyContent { mint: MINT.to_string(), hash })
+}
+
+#[test]
+fn listeners_verify_the_launched_audio() {
+    let mut deps = setup();
+    register(&mut deps, Some(sha256(b"the master recording"))).unwrap();
+    
+    let result = verify(&deps, sha256(b"the master recording"));
+    assert_eq!((result.matches, result.content_hash), (true, Some(sha256(b"the master recording"))));
+    let result = verify(&deps, sha256(b"a re-encoded copy"));
+    assert_eq!((result.matches, result.content_hash), (false, Some(sha256(b"the master recording"))));
+}
+
+#[test]
+fn tokens_without_a_hash_match_nothing() {
+    let mut deps = setup();
+    register(&mut deps, None).unwrap();
+    
+    let result = verify(&deps, sha256(b"the master recording"));
+    assert_eq!((result.matches, result.content_hash), (false, None));
+}
+
+#[test]
+fn content_hash_must_be_a_sha256_digest() {
+    for len in [0, 20, 31, 33, 64] {
+        let err = register(&mut setup(), Some(HexBinary::from(vec![1u8; len]))).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidContentHash {}));
+    }
+}
+
+#[test]
+fn unregistered_tokens_cannot_be_verified() {
+    let deps = setup();
+    
+    let err = query_err(&deps, QueryMsg::VerifyContent { mint: MINT.to_string(), hash: sha256(b"anything") });
+    assert!(matches!(err, ContractError::TokenNotFound { mint } if mint == MINT));
+}
@@ -0,0 +1,163 @@
+mod common;
+
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{coins, to_json_binary, Decimal, Decimal256, HexBinary, Response, Uint128};
+use cw20::Cw20ReceiveMsg;
+use hashmelody::{
+    error::ContractError,
+    msg::{DelistingResponse, ExecuteMsg, QueryMsg, ReceiveMsg},
+    state::{Delisting, TokenStatus, TOKEN_VAULTS}
+};
+use common::{attr, bank_send, buy, query_as, run, setup, TestDeps, MINT, PRICE};
+
+// What the vault keeps of each token sold: the price less the 2.5% fee and 5% royalty
+const VAULT_PER_TOKEN: u128 = PRICE * 925 / 1000;
+
+fn delist(deps: &mut TestDeps, deposit: u128) -> Result<Response, ContractError> {
+    let msg = ExecuteMsg::DelistToken { mint: MINT.to_string(), reason_uri: None };
+    run(deps, "admin", &coins(deposit, "inj"), msg)
+}
+
+fn redeem(deps: &mut TestDeps, holder: &str, amount: u128) -> Result<Response, ContractError> {
+    run(deps, holder, &coins(amount, MINT), ExecuteMsg::RedeemDelisted { mint: MINT.to_string() })
+}
+
+fn delisting(deps: &TestDeps) -> Delisting {
+    query_as::<DelistingResponse>(deps, QueryMsg::GetDelisting { mint: MINT.to_string() })
+        .delisting
+        .unwrap()
+}
+
+#[test]
+fn delisting_requires_vault_reserve() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 3, None).unwrap();
+    
+    let err = delist(&mut deps, VAULT_PER_TOKEN).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::InvalidDeposit { expected, .. } if expected.u128() == 3 * VAULT_PER_TOKEN
+    ));
+    
+    delist(&mut deps, 3 * VAULT_PER_TOKEN).unwrap();
+    let delisting = delisting(&deps);
+    assert_eq!(delisting.reserve.u128(), 3 * VAULT_PER_TOKEN);
+    assert_eq!(delisting.supply.u128(), 3);
+    assert_eq!(delisting.delisted_at, mock_env().block.time.seconds());
+}
+
+#[test]
+fn reserve_counts_only_inj_paid_in() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 1, None).unwrap();
+    
+    // Carl pays for two more tokens in a CW20 token priced one to one with INJ
+    let msg = ExecuteMsg::AddQuoteToken { token: "usd".to_string(), inj_rate: Decimal::one() };
+    run(&mut deps, "admin", &[], msg).unwrap();
+    let payload = ReceiveMsg::Purchase { mint: MINT.to_string(), min_tokens_out: Uint128::new(2), referrer: None, proof: None };
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: "carl".to_string(),
+        amount: Uint128::new(2 * PRICE),
+        msg: to_json_binary(&payload).unwrap(),
+    });
+    run(&mut deps, "usd", &[], msg).unwrap();
+    
+    // Only bob's INJ can back redemptions, shared over all three tokens
+    let err = delist(&mut deps, 3 * VAULT_PER_TOKEN).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidDeposit { expected, .. } if expected.u128() == VAULT_PER_TOKEN));
+    delist(&mut deps, VAULT_PER_TOKEN).unwrap();
+    assert_eq!(delisting(&deps).rate, Decimal256::from_ratio(VAULT_PER_TOKEN, 3u128));
+}
+
+#[test]
+fn vaults_from_before_cw20_payments_hold_only_inj() {
+    let mut deps = setup();
+    let mut vault = TOKEN_VAULTS.load(deps.as_ref().storage, MINT.as_bytes()).unwrap();
+    vault.total_collected = Uint128::new(2 * VAULT_PER_TOKEN);
+    vault.native_collected = None;
+    TOKEN_VAULTS.save(deps.as_mut().storage, MINT.as_bytes(), &vault).unwrap();
+    
+    // The legacy INJ is kept as the next purchase starts tracking it separately
+    buy(&mut deps, "bob", 1, None).unwrap();
+    delist(&mut deps, 3 * VAULT_PER_TOKEN).unwrap();
+}
+
+#[test]
+fn delisted_tokens_stop_trading() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 1, None).unwrap();
+    delist(&mut deps, VAULT_PER_TOKEN).unwrap();
+    
+    let err = buy(&mut deps, "carl", 1, None).unwrap_err();
+    assert!(matches!(err, ContractError::TokenNotTradable { status: TokenStatus::Delisted, .. }));
+    
+    let err = delist(&mut deps, VAULT_PER_TOKEN).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidTokenStatus { .. }));
+}
+
+#[test]
+fn redemption_needs_a_delisted_token() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 1, None).unwrap();
+    
+    let err = redeem(&mut deps, "bob", 1).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidTokenStatus { status: TokenStatus::Live }));
+}
+
+#[test]
+fn redemption_requires_song_tokens() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 1, None).unwrap();
+    delist(&mut deps, VAULT_PER_TOKEN).unwrap();
+    
+    let err = run(&mut deps, "bob", &coins(1, "inj"), ExecuteMsg::RedeemDelisted { mint: MINT.to_string() }).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidRedeemFunds { .. }));
+}
+
+#[test]
+fn holders_redeem_pro_rata_until_supply_is_exhausted() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 3, None).unwrap();
+    buy(&mut deps, "dan", 1, None).unwrap();
+    delist(&mut deps, 4 * VAULT_PER_TOKEN).unwrap();
+    
+    let res = redeem(&mut deps, "bob", 2).unwrap();
+    assert_eq!(bank_send(&res), ("bob".to_string(), coins(2 * VAULT_PER_TOKEN, "inj")));
+    assert_eq!(attr(&res, "burn_amount"), "2");
+    
+    let res = redeem(&mut deps, "dan", 1).unwrap();
+    assert_eq!(bank_send(&res), ("dan".to_string(), coins(VAULT_PER_TOKEN, "inj")));
+    
+    let res = redeem(&mut deps, "bob", 1).unwrap();
+    assert_eq!(bank_send(&res), ("bob".to_string(), coins(VAULT_PER_TOKEN, "inj")));
+    
+    let delisting = delisting(&deps);
+    assert_eq!(delisting.redeemed.u128(), 4);
+    assert_eq!(delisting.paid_out, delisting.reserve);
+    
+    // Tokens beyond the supply the rate was fixed against cannot be redeemed
+    let err = redeem(&mut deps, "eve", 1).unwrap_err();
+    assert!(matches!(err, ContractError::ExceedsRedeemableSupply { remaining } if remaining.is_zero()));
+}
+
+#[test]
+fn airdrop_deposits_count_toward_redemption_supply() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 3, None).unwrap();
+    let register = ExecuteMsg::RegisterAirdrop {
+        mint: MINT.to_string(),
+        merkle_root: HexBinary::from(vec![1u8; 32]),
+        total_amount: Uint128::new(1),
+        expiry: mock_env().block.time.seconds() + 100,
+    };
+    run(&mut deps, "alice", &coins(1, MINT), register).unwrap();
+    delist(&mut deps, 3 * VAULT_PER_TOKEN).unwrap();
+    
+    // The reserve is spread over four tokens, so three redeem for three quarters of it
+    assert_eq!(delisting(&deps).supply.u128(), 4);
+    let res = redeem(&mut deps, "bob", 3).unwrap();
+    assert_eq!(bank_send(&res), ("bob".to_string(), coins(3 * VAULT_PER_TOKEN * 3 / 4, "inj")));
+    
+    let err = redeem(&mut deps, "bob", 2).unwrap_err();
+    assert!(matches!(err, ContractError::ExceedsRedeemableSupply { remaining } if remaining.u128() == 1));
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use cosmwasm_std::Uint128;
+use hashmelody::{
+    error::ContractError,
+    msg::{ExecuteMsg, QueryMsg, TokenPriceResponse}
+};
+use common::{query_as, query_err, register_song, run, setup, TestDeps, MINT, PRICE};
+
+fn price_err(deps: &TestDeps, mint: &str) -> ContractError {
+    query_err(deps, QueryMsg::GetTokenPrice { mint: mint.to_string() })
+}
+
+#[test]
+fn malformed_denoms_are_rejected() {
+    let deps = setup();
+    
+    for mint in ["song", "factory/alice", "factory//song", "factory/alice/", "ibc/alice/song", ""] {
+        assert!(matches!(price_err(&deps, mint), ContractError::InvalidDenom {}), "{}", mint);
+    }
+}
+
+#[test]
+fn subdenoms_may_contain_slashes() {
+    let mut deps = setup();
+    let mint = register_song(&mut deps, "alice", "album/track", 2, "Track");
+    
+    assert_eq!(mint, "factory/alice/album/track");
+    assert!(matches!(price_err(&deps, &mint), ContractError::TokenNotInitialized { .. }));
+}
+
+#[test]
+fn unknown_tokens_are_reported_as_such() {
+    let deps = setup();
+    
+    let err = price_err(&deps, "factory/alice/other");
+    assert!(matches!(err, ContractError::TokenNotFound { mint } if mint == "factory/alice/other"));
+    let err = query_err(&deps, QueryMsg::GetTokenMetadata { mint: "factory/alice/other".to_string() });
+    assert!(matches!(err, ContractError::TokenNotFound { .. }));
+}
+
+#[test]
+fn partially_set_up_tokens_name_the_missing_part() {
+    let mut deps = setup();
+    let mint = register_song(&mut deps, "bob", "cover", 2, "Cover");
+    
+    let err = price_err(&deps, &mint);
+    assert!(matches!(err, ContractError::TokenNotInitialized { missing, .. } if missing == "oracle"));
+    
+    let err = query_err(&deps, QueryMsg::GetVaultBalance { mint: mint.clone() });
+    assert!(matches!(err, ContractError::TokenNotInitialized { missing, .. } if missing == "vault"));
+    
+    // The oracle comes with the vault, so the token is priced from then on
+    run(&mut deps, "bob", &[], ExecuteMsg::InitializeTokenOracle { mint: mint.clone() }).unwrap();
+    let price: TokenPriceResponse = query_as(&deps, QueryMsg::GetTokenPrice { mint });
+    assert_eq!(price.price.u128(), PRICE);
+}
+
+#[test]
+fn purchases_of_bad_denoms_fail_with_typed_errors() {
+    let mut deps = setup();
+    let buy = |mint: &str| ExecuteMsg::PurchaseToken {
+        mint: mint.to_string(),
+        amount_tokens: Uint128::one(),
+        referrer: None,
+        proof: None,
+    };
+    
+    let err = run(&mut deps, "bob", &[], buy("not-a-denom")).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidDenom {}));
+    let err = run(&mut deps, "bob", &[], buy("factory/alice/other")).unwrap_err();
+    assert!(matches!(err, ContractError::TokenNotFound { .. }));
+    assert!(run(&mut deps, "bob", &[], buy(MINT)).is_err());
+}
@@ -0,0 +1,97 @@
+mod common;
+
+use cosmwasm_std::testing::MOCK_CONTRACT_ADDR;
+use cosmwasm_std::{coins, Coin, Uint128};
+use hashmelody::{
+    error::ContractError,
+    msg::{ClaimableResponse, ExecuteMsg, QueryMsg, SplitRecipientMsg}
+};
+use common::{attr, bank_send, bank_sends, buy, query_as, run, setup, TestDeps, MINT, PRICE};
+
+fn claimable(deps: &TestDeps, address: &str) -> Vec<Coin> {
+    query_as::<ClaimableResponse>(deps, QueryMsg::GetClaimable { address: address.to_string() }).balances
+}
+
+#[test]
+fn purchase_accrues_platform_fee_and_royalty() {
+    let mut deps = setup();
+    let res = buy(&mut deps, "bob", 2, None).unwrap();
+    
+    // 2.5% platform fee, of which holders take 20%; nothing is staked, so the platform keeps the staker share
+    assert_eq!(attr(&res, "platform_fee"), "50000000");
+    assert_eq!(attr(&res, "holder_reward"), "10000000");
+    assert_eq!(attr(&res, "staker_reward"), "0");
+    assert_eq!(claimable(&deps, "platform"), coins(40_000_000, "inj"));
+    
+    // 5% royalty to the creator, the rest sent to the vault and the surplus back to the buyer
+    assert_eq!(claimable(&deps, "alice"), coins(100_000_000, "inj"));
+    let sends = bank_sends(&res);
+    assert_eq!(sends[0].1, coins(2 * PRICE - 150_000_000, "inj"));
+    assert_eq!(sends[1], ("bob".to_string(), coins(98 * PRICE, "inj")));
+    assert_eq!(attr(&res, "refund"), (98 * PRICE).to_string());
+}
+
+#[test]
+fn fees_accumulate_across_purchases() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 1, None).unwrap();
+    buy(&mut deps, "carl", 3, None).unwrap();
+    
+    assert_eq!(claimable(&deps, "platform"), coins(80_000_000, "inj"));
+    assert_eq!(claimable(&deps, "alice"), coins(200_000_000, "inj"));
+}
+
+#[test]
+fn royalty_follows_revenue_split() {
+    let mut deps = setup();
+    let recipients = vec![
+        SplitRecipientMsg { address: "alice".to_string(), weight_bps: 7_000 },
+        SplitRecipientMsg { address: "producer".to_string(), weight_bps: 3_000 },
+    ];
+    run(&mut deps, "alice", &[], ExecuteMsg::SetRevenueSplit { mint: MINT.to_string(), recipients }).unwrap();
+    buy(&mut deps, "bob", 2, None).unwrap();
+    
+    assert_eq!(claimable(&deps, "alice"), coins(70_000_000, "inj"));
+    assert_eq!(claimable(&deps, "producer"), coins(30_000_000, "inj"));
+}
+
+#[test]
+fn claim_pays_out_and_clears_balance() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 2, None).unwrap();
+    
+    let res = run(&mut deps, "alice", &[], ExecuteMsg::ClaimFees { denom: "inj".to_string() }).unwrap();
+    assert_eq!(bank_send(&res), ("alice".to_string(), coins(100_000_000, "inj")));
+    assert!(claimable(&deps, "alice").is_empty());
+    
+    // The platform's balance is untouched by the artist's claim
+    assert_eq!(claimable(&deps, "platform"), coins(40_000_000, "inj"));
+}
+
+#[test]
+fn claim_without_balance_fails() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 1, None).unwrap();
+    run(&mut deps, "alice", &[], ExecuteMsg::ClaimFees { denom: "inj".to_string() }).unwrap();
+    
+    let err = run(&mut deps, "alice", &[], ExecuteMsg::ClaimFees { denom: "inj".to_string() }).unwrap_err();
+    assert!(matches!(err, ContractError::NothingToClaim {}));
+    let err = run(&mut deps, "bob", &[], ExecuteMsg::ClaimFees { denom: "inj".to_string() }).unwrap_err();
+    assert!(matches!(err, ContractError::NothingToClaim {}));
+}
+
+#[test]
+fn accrued_fees_are_not_recoverable() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 2, None).unwrap();
+    
+    // Accrued fees are liabilities, so recovering the contract's INJ cannot take them
+    deps.querier.update_balance(MOCK_CONTRACT_ADDR, coins(150_000_000, "inj"));
+    let msg = ExecuteMsg::RecoverTokens {
+        asset: "inj".to_string(),
+        amount: Uint128::new(1),
+        recipient: "admin".to_string(),
+    };
+    let err = run(&mut deps, "admin", &[], msg).unwrap_err();
+    assert!(matches!(err, ContractError::ExceedsRecoverable { available } if available.is_zero()));
+}
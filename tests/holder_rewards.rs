@@ -0,0 +1,171 @@
+mod common;
+
+use cosmwasm_std::{coins, Addr, Decimal256, Uint128};
+use cw_storage_plus::Map;
+use serde::{Deserialize, Serialize};
+use hashmelody::{
+    error::ContractError,
+    msg::{ExecuteMsg, HolderRewardsResponse, QueryMsg, RewardPoolResponse}
+};
+use common::{attr, bank_send, buy, query_as, run, setup, TestDeps, MINT};
+
+fn holder_rewards(deps: &TestDeps, address: &str) -> HolderRewardsResponse {
+    query_as(deps, QueryMsg::GetHolderRewards { mint: MINT.to_string(), address: address.to_string() })
+}
+
+fn reward_pool(deps: &TestDeps) -> RewardPoolResponse {
+    query_as(deps, QueryMsg::GetRewardPool { mint: MINT.to_string() })
+}
+
+fn update_views(deps: &mut TestDeps, views: u64) {
+    let msg = ExecuteMsg::UpdateOracle { mint: MINT.to_string(), new_view_count: views };
+    run(deps, "oracle", &[], msg).unwrap();
+}
+
+#[test]
+fn purchases_fill_pending_pool() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 1, None).unwrap();
+    buy(&mut deps, "carl", 3, None).unwrap();
+    
+    // 20% of each 25_000_000 per-token platform fee waits for the next view update
+    let pool = reward_pool(&deps).pool;
+    assert_eq!(pool.pending.u128(), 20_000_000);
+    assert_eq!(pool.total_holdings.u128(), 4);
+    
+    // Bob was the only holder when carl paid, and nobody held when bob paid
+    let bob = holder_rewards(&deps, "bob");
+    assert_eq!((bob.pending.u128(), bob.claimable.u128()), (20_000_000, 0));
+    assert!(holder_rewards(&deps, "carl").pending.is_zero());
+}
+
+#[test]
+fn view_update_releases_rewards_pro_rata() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 1, None).unwrap();
+    buy(&mut deps, "carl", 3, None).unwrap();
+    buy(&mut deps, "dan", 4, None).unwrap();
+    update_views(&mut deps, 100);
+    
+    // Dan's 20_000_000 is shared 1:3 between bob and carl, on top of bob's earlier 20_000_000
+    assert!(reward_pool(&deps).pool.pending.is_zero());
+    assert_eq!(holder_rewards(&deps, "bob"), HolderRewardsResponse {
+        holdings: Uint128::new(1),
+        pending: Uint128::zero(),
+        claimable: Uint128::new(25_000_000),
+    });
+    assert_eq!(holder_rewards(&deps, "carl"), HolderRewardsResponse {
+        holdings: Uint128::new(3),
+        pending: Uint128::zero(),
+        claimable: Uint128::new(15_000_000),
+    });
+    assert!(holder_rewards(&deps, "dan").claimable.is_zero());
+}
+
+#[test]
+fn buying_just_before_a_release_earns_nothing_from_it() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 1, None).unwrap();
+    buy(&mut deps, "carl", 1, None).unwrap();
+    
+    // Dan buys a large position right before the view update
+    buy(&mut deps, "dan", 8, None).unwrap();
+    update_views(&mut deps, 100);
+    
+    // His own fee went to bob and carl, and nothing released was earned on his tokens
+    assert_eq!(holder_rewards(&deps, "bob").claimable.u128(), 30_000_000);
+    assert_eq!(holder_rewards(&deps, "carl").claimable.u128(), 20_000_000);
+    assert_eq!(holder_rewards(&deps, "dan"), HolderRewardsResponse {
+        holdings: Uint128::new(8),
+        pending: Uint128::zero(),
+        claimable: Uint128::zero(),
+    });
+}
+
+#[test]
+fn later_buyers_do_not_share_released_rewards() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 1, None).unwrap();
+    update_views(&mut deps, 100);
+    let res = buy(&mut deps, "carl", 1, None).unwrap();
+    let carl_fee: u128 = attr(&res, "holder_reward").parse().unwrap();
+    
+    // Carl's fee goes to bob, but only once views update again
+    let bob = holder_rewards(&deps, "bob");
+    assert_eq!((bob.claimable.u128(), bob.pending.u128()), (5_000_000, carl_fee));
+    assert!(holder_rewards(&deps, "carl").claimable.is_zero());
+    
+    update_views(&mut deps, 200);
+    assert_eq!(holder_rewards(&deps, "bob").claimable.u128(), 5_000_000 + carl_fee);
+    assert!(holder_rewards(&deps, "carl").claimable.is_zero());
+}
+
+#[test]
+fn staked_tokens_stop_earning_holder_rewards() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 2, None).unwrap();
+    buy(&mut deps, "carl", 2, None).unwrap();
+    run(&mut deps, "bob", &coins(2, MINT), ExecuteMsg::Stake { mint: MINT.to_string() }).unwrap();
+    
+    // Bob's tokens left his holdings, so carl takes all of dan's fee; bob keeps what he earned before
+    assert_eq!(reward_pool(&deps).pool.total_holdings.u128(), 2);
+    buy(&mut deps, "dan", 2, None).unwrap();
+    update_views(&mut deps, 100);
+    assert_eq!(holder_rewards(&deps, "bob").claimable.u128(), 20_000_000);
+    assert_eq!(holder_rewards(&deps, "carl").claimable.u128(), 10_000_000);
+}
+
+#[test]
+fn claim_pays_out_once() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 1, None).unwrap();
+    update_views(&mut deps, 100);
+    
+    let res = run(&mut deps, "bob", &[], ExecuteMsg::ClaimHolderRewards { mint: MINT.to_string() }).unwrap();
+    assert_eq!(bank_send(&res), ("bob".to_string(), coins(5_000_000, "inj")));
+    assert!(holder_rewards(&deps, "bob").claimable.is_zero());
+    
+    let err = run(&mut deps, "bob", &[], ExecuteMsg::ClaimHolderRewards { mint: MINT.to_string() }).unwrap_err();
+    assert!(matches!(err, ContractError::NothingToClaim {}));
+}
+
+// Pools and positions as stored before fees were shared out at purchase time
+#[derive(Serialize, Deserialize)]
+struct LegacyRewardPool {
+    pending: Uint128,
+    reward_per_token: Decimal256,
+    total_holdings: Uint128,
+}
+
+#[derive(Serialize, Deserialize)]
+struct LegacyHolderRewards {
+    holdings: Uint128,
+    reward_per_token_paid: Decimal256,
+    accrued: Uint128,
+}
+
+#[test]
+fn legacy_pending_fees_go_to_holders_at_the_next_release() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 2, None).unwrap();
+    
+    // Rewrite the pool and bob's position in their old layout, with the fee not yet shared out
+    let pools: Map<&[u8], LegacyRewardPool> = Map::new("reward_pools");
+    let holders: Map<(&[u8], &Addr), LegacyHolderRewards> = Map::new("holder_rewards");
+    let pool = LegacyRewardPool {
+        pending: Uint128::new(10_000_000),
+        reward_per_token: Decimal256::percent(50),
+        total_holdings: Uint128::new(2),
+    };
+    pools.save(deps.as_mut().storage, MINT.as_bytes(), &pool).unwrap();
+    let bob = LegacyHolderRewards {
+        holdings: Uint128::new(2),
+        reward_per_token_paid: Decimal256::percent(50),
+        accrued: Uint128::new(7),
+    };
+    holders.save(deps.as_mut().storage, (MINT.as_bytes(), &Addr::unchecked("bob")), &bob).unwrap();
+    
+    assert_eq!(holder_rewards(&deps, "bob").claimable.u128(), 7);
+    update_views(&mut deps, 100);
+    assert_eq!(holder_rewards(&deps, "bob").claimable.u128(), 10_000_007);
+}
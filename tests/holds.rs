@@ -0,0 +1,145 @@
+mod common;
+
+use cosmwasm_std::{coins, Response, Uint128};
+use hashmelody::{
+    error::ContractError,
+    msg::{DisputesResponse, ExecuteMsg, QueryMsg, TokenStatusResponse},
+    state::{Dispute, HoldOutcome, TokenStatus}
+};
+use common::{buy, query_as, register_song, run, setup, TestDeps, MINT, PRICE};
+
+// What the vault keeps of each token sold: the price less the 2.5% fee and 5% royalty
+const VAULT_PER_TOKEN: u128 = PRICE * 925 / 1000;
+
+const REASON: &str = "https://example.com/claims/1";
+
+fn moderated() -> TestDeps {
+    let mut deps = setup();
+    run(&mut deps, "admin", &[], ExecuteMsg::SetModerator { moderator: Some("moderator".to_string()) }).unwrap();
+    deps
+}
+
+fn hold(deps: &mut TestDeps, sender: &str, mint: &str) -> Result<Response, ContractError> {
+    run(deps, sender, &[], ExecuteMsg::PlaceHold { mint: mint.to_string(), reason_uri: REASON.to_string() })
+}
+
+fn resolve(deps: &mut TestDeps, sender: &str, outcome: HoldOutcome, deposit: u128) -> Result<Response, ContractError> {
+    let msg = ExecuteMsg::ResolveHold { mint: MINT.to_string(), outcome, resolution_uri: None };
+    run(deps, sender, &coins(deposit, "inj"), msg)
+}
+
+fn status(deps: &TestDeps, mint: &str) -> TokenStatus {
+    query_as::<TokenStatusResponse>(deps, QueryMsg::GetTokenStatus { mint: mint.to_string() }).status
+}
+
+fn disputes(deps: &TestDeps) -> Vec<Dispute> {
+    let msg = QueryMsg::GetDisputes { mint: MINT.to_string(), start_after: None, limit: None };
+    query_as::<DisputesResponse>(deps, msg).disputes
+}
+
+fn assert_held(result: Result<Response, ContractError>) {
+    assert!(matches!(result.unwrap_err(), ContractError::InvalidTokenStatus { status: TokenStatus::OnHold }));
+}
+
+#[test]
+fn only_the_moderator_places_and_resolves_holds() {
+    let err = hold(&mut setup(), "admin", MINT).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    
+    let mut deps = moderated();
+    let err = hold(&mut deps, "bob", MINT).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    let err = resolve(&mut deps, "moderator", HoldOutcome::Release, 0).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidTokenStatus { status: TokenStatus::Live }));
+    
+    hold(&mut deps, "moderator", MINT).unwrap();
+    assert_held(hold(&mut deps, "moderator", MINT));
+    let err = resolve(&mut deps, "bob", HoldOutcome::Release, 0).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+}
+
+#[test]
+fn held_tokens_move_no_value() {
+    let mut deps = moderated();
+    buy(&mut deps, "bob", 2, None).unwrap();
+    run(&mut deps, "bob", &coins(1, MINT), ExecuteMsg::Stake { mint: MINT.to_string() }).unwrap();
+    hold(&mut deps, "moderator", MINT).unwrap();
+    
+    let err = buy(&mut deps, "carl", 1, None).unwrap_err();
+    assert!(matches!(err, ContractError::TokenNotTradable { status: TokenStatus::OnHold, .. }));
+    let mint = MINT.to_string();
+    assert_held(run(&mut deps, "oracle", &[], ExecuteMsg::UpdateOracle { mint: mint.clone(), new_view_count: 10 }));
+    assert_held(run(&mut deps, "admin", &[], ExecuteMsg::MintToken { mint: mint.clone(), recipient: "bob".to_string(), amount: Uint128::one() }));
+    assert_held(run(&mut deps, "bob", &coins(1, MINT), ExecuteMsg::Stake { mint: mint.clone() }));
+    assert_held(run(&mut deps, "bob", &[], ExecuteMsg::Unstake { mint: mint.clone(), amount: Uint128::one() }));
+    assert_held(run(&mut deps, "bob", &[], ExecuteMsg::ClaimHolderRewards { mint: mint.clone() }));
+    assert_held(run(&mut deps, "bob", &[], ExecuteMsg::ClaimStakingRewards { mint: mint.clone() }));
+}
+
+#[test]
+fn a_hold_cannot_be_bypassed() {
+    let mut deps = moderated();
+    let demo = register_song(&mut deps, "alice", "demo", 2, "Demo");
+    run(&mut deps, "alice", &[], ExecuteMsg::InitializeTokenOracle { mint: demo.clone() }).unwrap();
+    hold(&mut deps, "moderator", &demo).unwrap();
+    
+    // Setting up the vault would open the token for trading; it is refused while held
+    let err = run(&mut deps, "bob", &[], ExecuteMsg::SetupVaultAccount { mint: demo.clone() }).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    assert_held(run(&mut deps, "alice", &[], ExecuteMsg::SetupVaultAccount { mint: demo.clone() }));
+    assert_eq!(status(&deps, &demo), TokenStatus::OnHold);
+    
+    // Releasing it restores the draft, which its creator can then open
+    let msg = ExecuteMsg::ResolveHold { mint: demo.clone(), outcome: HoldOutcome::Release, resolution_uri: None };
+    run(&mut deps, "moderator", &[], msg).unwrap();
+    assert_eq!(status(&deps, &demo), TokenStatus::Draft);
+    run(&mut deps, "alice", &[], ExecuteMsg::SetupVaultAccount { mint: demo.clone() }).unwrap();
+    assert_eq!(status(&deps, &demo), TokenStatus::Live);
+}
+
+#[test]
+fn release_restores_the_previous_status() {
+    let mut deps = moderated();
+    hold(&mut deps, "moderator", MINT).unwrap();
+    assert_eq!(status(&deps, MINT), TokenStatus::OnHold);
+    
+    resolve(&mut deps, "moderator", HoldOutcome::Release, 0).unwrap();
+    assert_eq!(status(&deps, MINT), TokenStatus::Live);
+    buy(&mut deps, "bob", 1, None).unwrap();
+    
+    let disputes = disputes(&deps);
+    assert_eq!(disputes.len(), 1);
+    let resolution = disputes[0].resolution.as_ref().unwrap();
+    assert_eq!((resolution.outcome, resolution.resolved_by.as_str()), (HoldOutcome::Release, "moderator"));
+}
+
+#[test]
+fn moderator_delists_a_held_token() {
+    let mut deps = moderated();
+    buy(&mut deps, "bob", 2, None).unwrap();
+    hold(&mut deps, "moderator", MINT).unwrap();
+    
+    // The vault's INJ comes back with the delisting to back redemptions
+    let err = resolve(&mut deps, "moderator", HoldOutcome::Delist, VAULT_PER_TOKEN).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidDeposit { expected, .. } if expected.u128() == 2 * VAULT_PER_TOKEN));
+    resolve(&mut deps, "moderator", HoldOutcome::Delist, 2 * VAULT_PER_TOKEN).unwrap();
+    assert_eq!(status(&deps, MINT), TokenStatus::Delisted);
+    assert_eq!(disputes(&deps)[0].resolution.as_ref().unwrap().outcome, HoldOutcome::Delist);
+    
+    // Holders redeem, and a delisted token cannot be held or released
+    run(&mut deps, "bob", &coins(2, MINT), ExecuteMsg::RedeemDelisted { mint: MINT.to_string() }).unwrap();
+    let err = hold(&mut deps, "moderator", MINT).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidStatusTransition { from: TokenStatus::Delisted, .. }));
+    let err = resolve(&mut deps, "moderator", HoldOutcome::Release, 0).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidTokenStatus { status: TokenStatus::Delisted }));
+}
+
+#[test]
+fn authority_delisting_settles_an_open_hold() {
+    let mut deps = moderated();
+    hold(&mut deps, "moderator", MINT).unwrap();
+    
+    run(&mut deps, "admin", &[], ExecuteMsg::DelistToken { mint: MINT.to_string(), reason_uri: None }).unwrap();
+    let resolution = disputes(&deps)[0].resolution.clone().unwrap();
+    assert_eq!((resolution.outcome, resolution.resolved_by.as_str()), (HoldOutcome::Delist, "admin"));
+}
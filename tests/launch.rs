@@ -0,0 +1,141 @@
+mod common;
+
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{coins, Response, Uint128};
+use hashmelody::{
+    error::ContractError,
+    msg::{ExecuteMsg, LaunchConfigResponse, QueryMsg}
+};
+use common::{at, buy, query_as, run, run_at, setup, TestDeps, MINT, PRICE};
+
+// Seconds after the mock block at which the launch starts
+const START: u64 = 100;
+
+fn launch_msg(allowlist_duration: Option<u64>, max_per_address: Option<u128>, max_per_tx: Option<u128>) -> ExecuteMsg {
+    ExecuteMsg::SetLaunchConfig {
+        mint: MINT.to_string(),
+        start_time: mock_env().block.time.seconds() + START,
+        allowlist_duration,
+        protection_duration: 200,
+        max_per_address: max_per_address.map(Uint128::new),
+        max_per_tx: max_per_tx.map(Uint128::new),
+        presale: None,
+    }
+}
+
+fn launch(deps: &mut TestDeps, allowlist_duration: Option<u64>, max_per_address: Option<u128>, max_per_tx: Option<u128>) {
+    run(deps, "alice", &[], launch_msg(allowlist_duration, max_per_address, max_per_tx)).unwrap();
+}
+
+fn buy_at(deps: &mut TestDeps, seconds: u64, buyer: &str, amount: u128) -> Result<Response, ContractError> {
+    let msg = ExecuteMsg::PurchaseToken {
+        mint: MINT.to_string(),
+        amount_tokens: Uint128::new(amount),
+        referrer: None,
+        proof: None,
+    };
+    run_at(deps, at(seconds), buyer, &coins(100 * PRICE, "inj"), msg)
+}
+
+fn allowlist(deps: &mut TestDeps, add: &[&str], remove: &[&str]) -> Result<Response, ContractError> {
+    let msg = ExecuteMsg::UpdateLaunchAllowlist {
+        mint: MINT.to_string(),
+        add: add.iter().map(|a| a.to_string()).collect(),
+        remove: remove.iter().map(|a| a.to_string()).collect(),
+    };
+    run(deps, "alice", &[], msg)
+}
+
+#[test]
+fn launch_config_is_validated_and_locked() {
+    let mut deps = setup();
+    
+    let err = run(&mut deps, "bob", &[], launch_msg(None, Some(5), None)).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    for msg in [launch_msg(None, Some(0), None), launch_msg(None, None, Some(0))] {
+        let err = run(&mut deps, "alice", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidLaunchConfig {}));
+    }
+    
+    launch(&mut deps, Some(50), Some(5), Some(2));
+    let config: LaunchConfigResponse = query_as(&deps, QueryMsg::GetLaunchConfig { mint: MINT.to_string() });
+    let now = mock_env().block.time.seconds();
+    let config = config.config.unwrap();
+    assert_eq!(config.allowlist_end_time, Some(now + START + 50));
+    assert_eq!(config.protection_end_time, now + START + 200);
+    
+    // Settings are fixed once someone has bought
+    allowlist(&mut deps, &["bob"], &[]).unwrap();
+    buy_at(&mut deps, START, "bob", 1).unwrap();
+    let err = run(&mut deps, "alice", &[], launch_msg(None, None, None)).unwrap_err();
+    assert!(matches!(err, ContractError::LaunchLocked {}));
+}
+
+#[test]
+fn nothing_sells_before_the_start_without_a_presale() {
+    let mut deps = setup();
+    launch(&mut deps, None, None, None);
+    
+    let err = buy_at(&mut deps, START - 1, "bob", 1).unwrap_err();
+    assert!(matches!(err, ContractError::LaunchNotStarted { .. }));
+    buy_at(&mut deps, START, "bob", 1).unwrap();
+}
+
+#[test]
+fn transaction_cap_applies_to_every_purchase() {
+    let mut deps = setup();
+    launch(&mut deps, None, None, Some(2));
+    
+    let err = buy_at(&mut deps, START, "bob", 3).unwrap_err();
+    assert!(matches!(err, ContractError::ExceedsTransactionCap { max } if max == Uint128::new(2)));
+    buy_at(&mut deps, START, "bob", 2).unwrap();
+    
+    // The per-transaction cap outlives the protection window
+    let err = buy_at(&mut deps, START + 1_000, "carl", 3).unwrap_err();
+    assert!(matches!(err, ContractError::ExceedsTransactionCap { .. }));
+}
+
+#[test]
+fn address_cap_adds_up_purchases_until_protection_ends() {
+    let mut deps = setup();
+    launch(&mut deps, None, Some(5), None);
+    
+    buy_at(&mut deps, START, "bob", 3).unwrap();
+    let err = buy_at(&mut deps, START + 10, "bob", 3).unwrap_err();
+    assert!(matches!(err, ContractError::ExceedsAddressCap { max } if max == Uint128::new(5)));
+    buy_at(&mut deps, START + 10, "bob", 2).unwrap();
+    
+    // Other buyers have their own allowance
+    buy_at(&mut deps, START + 10, "carl", 5).unwrap();
+    
+    // After protection_duration anyone buys freely
+    buy_at(&mut deps, START + 200, "bob", 10).unwrap();
+}
+
+#[test]
+fn allowlist_phase_admits_only_listed_buyers() {
+    let mut deps = setup();
+    launch(&mut deps, Some(50), None, None);
+    
+    let msg = ExecuteMsg::UpdateLaunchAllowlist { mint: MINT.to_string(), add: vec!["bob".to_string()], remove: vec![] };
+    let err = run(&mut deps, "bob", &[], msg).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    
+    allowlist(&mut deps, &["bob", "carl"], &[]).unwrap();
+    allowlist(&mut deps, &[], &["carl"]).unwrap();
+    buy_at(&mut deps, START, "bob", 1).unwrap();
+    for buyer in ["carl", "dan"] {
+        let err = buy_at(&mut deps, START + 49, buyer, 1).unwrap_err();
+        assert!(matches!(err, ContractError::NotOnAllowlist {}));
+    }
+    
+    // The public sale follows the allowlist phase
+    buy_at(&mut deps, START + 50, "dan", 1).unwrap();
+}
+
+#[test]
+fn tokens_without_a_launch_config_trade_freely() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 5, None).unwrap();
+    buy(&mut deps, "bob", 5, None).unwrap();
+}
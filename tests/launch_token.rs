@@ -0,0 +1,148 @@
+mod common;
+
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{coins, BankMsg, Binary, CosmosMsg, HexBinary, Response, Uint128};
+use hashmelody::{
+    contract::instantiate,
+    error::ContractError,
+    msg::{ClaimableResponse, ExecuteMsg, InstantiateMsg, LaunchParams, QueryMsg, TokenStateResponse},
+    state::{SongDetails, TokenStatus}
+};
+use common::{query_as, run, TestDeps, PRICE};
+
+// INJ charged per launch
+const FEE: u128 = 2 * PRICE;
+
+fn platform() -> TestDeps {
+    let mut deps = mock_dependencies();
+    let msg = InstantiateMsg {
+        platform_wallet: "platform".to_string(),
+        oracle_authority: "oracle".to_string(),
+        referral_share_bps: None,
+        holder_reward_bps: None,
+        staker_reward_bps: None,
+        unbonding_period: None,
+        launch_fee: Some(Uint128::new(FEE)),
+        moderator: None,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("admin", &[]), msg).unwrap();
+    deps
+}
+
+fn mint() -> String {
+    format!("factory/{}/hit", mock_env().contract.address)
+}
+
+// Launch rules that would stop any public buyer: a sale that starts later, an allowlist, and
+// a cap of one token
+fn strict_launch() -> LaunchParams {
+    LaunchParams {
+        start_time: mock_env().block.time.seconds() + 100,
+        allowlist_duration: Some(50),
+        protection_duration: 200,
+        max_per_address: Some(Uint128::one()),
+        max_per_tx: Some(Uint128::one()),
+        presale: None,
+    }
+}
+
+fn launch(deps: &mut TestDeps, funds: u128, details: Option<SongDetails>, launch: Option<LaunchParams>, initial_buy: Option<u128>) -> Result<Response, ContractError> {
+    let msg = ExecuteMsg::LaunchToken {
+        subdenom: "hit".to_string(),
+        id: 9,
+        name: "Hit".to_string(),
+        music_uri: "https://cdn.example.com/hit.mp3".to_string(),
+        content_hash: Some(HexBinary::from(vec![0xab; 32])),
+        creator: None,
+        royalty_bps: 500,
+        mint_master_nft: None,
+        details,
+        launch,
+        initial_buy: initial_buy.map(Uint128::new),
+    };
+    run(deps, "dave", &coins(funds, "inj"), msg)
+}
+
+fn stargate(response: &Response, index: usize) -> (&str, &Binary) {
+    match &response.messages[index].msg {
+        CosmosMsg::Stargate { type_url, value } => (type_url.as_str(), value),
+        other => panic!("unexpected message {:?}", other),
+    }
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|window| window == needle)
+}
+
+#[test]
+fn launch_creates_the_denom_first() {
+    let mut deps = platform();
+    let details = SongDetails { symbol: Some("HITS".to_string()), ..SongDetails::default() };
+    let res = launch(&mut deps, FEE, Some(details), None, None).unwrap();
+    
+    // MsgCreateDenom { sender, subdenom, name, symbol }, with zero decimals left out
+    let (type_url, value) = stargate(&res, 0);
+    assert_eq!(type_url, "/injective.tokenfactory.v1beta1.MsgCreateDenom");
+    let contract = mock_env().contract.address;
+    let mut expected = vec![0x0a, contract.as_str().len() as u8];
+    expected.extend_from_slice(contract.as_bytes());
+    expected.extend_from_slice(b"\x12\x03hit\x1a\x03Hit\x22\x04HITS");
+    assert_eq!(value.as_slice(), expected.as_slice());
+    
+    // The bank metadata names the denom and commits to the audio file
+    let (type_url, value) = stargate(&res, 1);
+    assert_eq!(type_url, "/injective.tokenfactory.v1beta1.MsgSetDenomMetadata");
+    assert!(contains(value, mint().as_bytes()));
+    assert!(contains(value, b"https://cdn.example.com/hit.mp3"));
+    assert!(contains(value, "ab".repeat(32).as_bytes()));
+    
+    let state: TokenStateResponse = query_as(&deps, QueryMsg::GetTokenState { mint: mint() });
+    assert_eq!((state.metadata.creator.as_str(), state.status), ("dave", TokenStatus::Live));
+}
+
+#[test]
+fn launch_fee_is_charged_and_the_excess_refunded() {
+    let err = launch(&mut platform(), FEE - 1, None, None, None).unwrap_err();
+    assert!(matches!(err, ContractError::InsufficientFunds {}));
+    
+    let mut deps = platform();
+    let res = launch(&mut deps, FEE + 500, None, None, None).unwrap();
+    assert_eq!(res.messages.len(), 3);
+    assert_eq!(res.messages[2].msg, CosmosMsg::Bank(BankMsg::Send { to_address: "dave".to_string(), amount: coins(500, "inj") }));
+    
+    let platform: ClaimableResponse = query_as(&deps, QueryMsg::GetClaimable { address: "platform".to_string() });
+    assert_eq!(platform.balances, coins(FEE, "inj"));
+}
+
+#[test]
+fn initial_buy_is_paid_on_top_of_the_fee() {
+    let err = launch(&mut platform(), FEE + 3 * PRICE - 1, None, None, Some(3)).unwrap_err();
+    assert!(matches!(err, ContractError::InsufficientFunds {}));
+    
+    let mut deps = platform();
+    let res = launch(&mut deps, FEE + 3 * PRICE + 7, None, None, Some(3)).unwrap();
+    let event = res.events.iter().find(|event| event.ty == "initial_buy").unwrap();
+    assert!(event.attributes.iter().any(|attr| attr.key == "mint_to" && attr.value == "dave"));
+    let refund = res.attributes.iter().find(|attr| attr.key == "refund").unwrap();
+    assert_eq!(refund.value, "7");
+    
+    let state: TokenStateResponse = query_as(&deps, QueryMsg::GetTokenState { mint: mint() });
+    assert_eq!(state.circulating_supply, Uint128::new(3));
+}
+
+#[test]
+fn initial_buy_is_exempt_from_launch_rules() {
+    let mut deps = platform();
+    
+    // Before the start, off the allowlist and over both caps, the creator's buy still goes through
+    launch(&mut deps, FEE + 3 * PRICE, None, Some(strict_launch()), Some(3)).unwrap();
+    let state: TokenStateResponse = query_as(&deps, QueryMsg::GetTokenState { mint: mint() });
+    assert_eq!(state.circulating_supply, Uint128::new(3));
+    
+    // Later purchases, the creator's included, are held to them
+    for buyer in ["dave", "bob"] {
+        let msg = ExecuteMsg::PurchaseToken { mint: mint(), amount_tokens: Uint128::one(), referrer: None, proof: None };
+        let err = run(&mut deps, buyer, &coins(10 * PRICE, "inj"), msg).unwrap_err();
+        assert!(matches!(err, ContractError::LaunchNotStarted { .. }));
+    }
+}
@@ -0,0 +1,155 @@
+mod common;
+
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{
+    coins, from_json, to_json_binary, ContractResult, CosmosMsg, SystemError, SystemResult, Uint128,
+    WasmMsg, WasmQuery
+};
+use serde::{Deserialize, Serialize};
+use hashmelody::{
+    contract::instantiate,
+    error::ContractError,
+    msg::{ClaimableResponse, ExecuteMsg, InstantiateMsg, QueryMsg, RoyaltyTotalResponse, SplitRecipientMsg}
+};
+use common::{buy, query_as, run, TestDeps, MINT, PRICE};
+
+const COLLECTION: &str = "nfts";
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Cw721ExecuteMsg {
+    Mint { token_id: String, owner: String, token_uri: Option<String> },
+}
+
+#[derive(Serialize, Deserialize)]
+struct OwnerOfResponse {
+    owner: String,
+    approvals: Vec<String>,
+}
+
+// The collection reports `owner` for every token, or fails if there is none
+fn set_nft_owner(deps: &mut TestDeps, owner: Option<&str>) {
+    let owner = owner.map(str::to_string);
+    deps.querier.update_wasm(move |query| match (query, &owner) {
+        (WasmQuery::Smart { contract_addr, .. }, Some(owner)) if contract_addr == COLLECTION => {
+            let response = OwnerOfResponse { owner: owner.clone(), approvals: vec![] };
+            SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
+        },
+        _ => SystemResult::Err(SystemError::NoSuchContract { addr: COLLECTION.to_string() }),
+    });
+}
+
+fn song_msg(mint_master_nft: bool) -> ExecuteMsg {
+    ExecuteMsg::InitializeTokenMetadata {
+        id: 1,
+        name: "Song".to_string(),
+        music_uri: "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_string(),
+        content_hash: None,
+        mint: MINT.to_string(),
+        creator: None,
+        royalty_bps: 500,
+        vesting: None,
+        mint_master_nft: Some(mint_master_nft),
+        details: None,
+    }
+}
+
+fn platform() -> TestDeps {
+    let mut deps = mock_dependencies();
+    let msg = InstantiateMsg {
+        platform_wallet: "platform".to_string(),
+        oracle_authority: "oracle".to_string(),
+        referral_share_bps: None,
+        holder_reward_bps: None,
+        staker_reward_bps: None,
+        unbonding_period: None,
+        launch_fee: None,
+        moderator: None,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("admin", &[]), msg).unwrap();
+    deps
+}
+
+// Alice's song with a master NFT, which she has sold to erin
+fn sold_nft_deps() -> TestDeps {
+    let mut deps = platform();
+    run(&mut deps, "admin", &[], ExecuteMsg::SetMasterNftContract { contract: Some(COLLECTION.to_string()) }).unwrap();
+    run(&mut deps, "alice", &[], song_msg(true)).unwrap();
+    run(&mut deps, "alice", &[], ExecuteMsg::InitializeTokenOracle { mint: MINT.to_string() }).unwrap();
+    run(&mut deps, "alice", &[], ExecuteMsg::SetupVaultAccount { mint: MINT.to_string() }).unwrap();
+    set_nft_owner(&mut deps, Some("erin"));
+    deps
+}
+
+fn set_split(deps: &mut TestDeps, sender: &str) -> Result<(), ContractError> {
+    let recipients = vec![SplitRecipientMsg { address: sender.to_string(), weight_bps: 10_000 }];
+    run(deps, sender, &[], ExecuteMsg::SetRevenueSplit { mint: MINT.to_string(), recipients }).map(|_| ())
+}
+
+#[test]
+fn master_nft_needs_a_collection() {
+    let mut deps = platform();
+    
+    let err = run(&mut deps, "alice", &[], song_msg(true)).unwrap_err();
+    assert!(matches!(err, ContractError::MasterNftNotConfigured {}));
+    
+    let msg = ExecuteMsg::SetMasterNftContract { contract: Some(COLLECTION.to_string()) };
+    let err = run(&mut deps, "alice", &[], msg).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+}
+
+#[test]
+fn master_nft_is_minted_to_the_creator() {
+    let mut deps = platform();
+    run(&mut deps, "admin", &[], ExecuteMsg::SetMasterNftContract { contract: Some(COLLECTION.to_string()) }).unwrap();
+    
+    let res = run(&mut deps, "alice", &[], song_msg(true)).unwrap();
+    let msg = match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, msg, .. }) if contract_addr == COLLECTION => msg,
+        other => panic!("unexpected message {:?}", other),
+    };
+    let Cw721ExecuteMsg::Mint { token_id, owner, token_uri } = from_json(msg).unwrap();
+    assert_eq!((token_id.as_str(), owner.as_str()), (MINT, "alice"));
+    assert!(token_uri.unwrap().starts_with("ipfs://"));
+}
+
+#[test]
+fn rights_follow_the_master_nft() {
+    let mut deps = sold_nft_deps();
+    
+    // Alice gave up her rights with the NFT; erin holds them now
+    let err = set_split(&mut deps, "alice").unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    let err = run(&mut deps, "alice", &[], ExecuteMsg::FreezeMetadata { mint: MINT.to_string() }).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    set_split(&mut deps, "erin").unwrap();
+    run(&mut deps, "erin", &[], ExecuteMsg::FreezeMetadata { mint: MINT.to_string() }).unwrap();
+}
+
+#[test]
+fn royalties_are_tallied_for_the_nft_owner() {
+    let mut deps = sold_nft_deps();
+    buy(&mut deps, "bob", 2, None).unwrap();
+    
+    let erin: ClaimableResponse = query_as(&deps, QueryMsg::GetClaimable { address: "erin".to_string() });
+    assert_eq!(erin.balances, coins(2 * PRICE / 20, "inj"));
+    
+    // The royalty total goes to the address that was paid, not the original creator
+    let total = |deps: &TestDeps, creator: &str| {
+        query_as::<RoyaltyTotalResponse>(deps, QueryMsg::GetCreatorRoyalties { creator: creator.to_string() }).total
+    };
+    assert_eq!(total(&deps, "erin"), Uint128::new(2 * PRICE / 20));
+    assert_eq!(total(&deps, "alice"), Uint128::zero());
+}
+
+#[test]
+fn purchases_survive_a_broken_collection() {
+    let mut deps = sold_nft_deps();
+    set_nft_owner(&mut deps, None);
+    
+    // Royalties fall back to the creator, but nobody can act on the token's rights
+    buy(&mut deps, "bob", 1, None).unwrap();
+    let alice: ClaimableResponse = query_as(&deps, QueryMsg::GetClaimable { address: "alice".to_string() });
+    assert_eq!(alice.balances, coins(PRICE / 20, "inj"));
+    assert!(set_split(&mut deps, "alice").is_err());
+}
@@ -0,0 +1,137 @@
+mod common;
+
+use cosmwasm_std::{HexBinary, Response};
+use hashmelody::{
+    error::ContractError,
+    msg::{ExecuteMsg, MetadataHistoryResponse, QueryMsg, TokenMetadataResponse},
+    state::{FieldChange, SongDetails}
+};
+use common::{query_as, run, setup, TestDeps, MINT};
+
+const NEW_URI: &str = "ipfs://QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o";
+
+fn update(deps: &mut TestDeps, sender: &str, name: Option<&str>, music_uri: Option<&str>, details: Option<SongDetails>) -> Result<Response, ContractError> {
+    let msg = ExecuteMsg::UpdateTokenMetadata {
+        mint: MINT.to_string(),
+        name: name.map(str::to_string),
+        music_uri: music_uri.map(str::to_string),
+        content_hash: None,
+        details,
+    };
+    run(deps, sender, &[], msg)
+}
+
+fn metadata(deps: &TestDeps) -> TokenMetadataResponse {
+    query_as(deps, QueryMsg::GetTokenMetadata { mint: MINT.to_string() })
+}
+
+fn history(deps: &TestDeps) -> MetadataHistoryResponse {
+    query_as(deps, QueryMsg::GetMetadataHistory { mint: MINT.to_string(), start_after: None, limit: None })
+}
+
+fn change(field: &str, old: Option<&str>, new: Option<&str>) -> FieldChange {
+    FieldChange { field: field.to_string(), old: old.map(str::to_string), new: new.map(str::to_string) }
+}
+
+#[test]
+fn creator_updates_metadata_with_an_audit_trail() {
+    let mut deps = setup();
+    
+    let err = update(&mut deps, "bob", Some("Other"), None, None).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    let err = update(&mut deps, "alice", Some("Song"), None, None).unwrap_err();
+    assert!(matches!(err, ContractError::NoMetadataChanges {}));
+    
+    let details = SongDetails { genre: Some("Jazz".to_string()), ..SongDetails::default() };
+    update(&mut deps, "alice", Some("Renamed"), None, Some(details)).unwrap();
+    assert_eq!(metadata(&deps).name, "Renamed");
+    assert_eq!(metadata(&deps).details.genre.as_deref(), Some("Jazz"));
+    
+    // Registration is entry 0; the update lists only the fields it changed
+    let entries = history(&deps).entries;
+    assert_eq!(entries.len(), 2);
+    assert_eq!((entries[1].seq, entries[1].action.as_str(), entries[1].editor.as_str()), (1, "update", "alice"));
+    assert_eq!(entries[1].changes, vec![change("name", Some("Song"), Some("Renamed")), change("genre", None, Some("Jazz"))]);
+}
+
+#[test]
+fn invalid_updates_are_rejected() {
+    let mut deps = setup();
+    
+    let err = update(&mut deps, "alice", Some(" "), None, None).unwrap_err();
+    assert!(matches!(err, ContractError::EmptyField { .. }));
+    let err = update(&mut deps, "alice", None, Some("http://example.com/song.mp3"), None).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidUri { .. }));
+    let details = SongDetails { isrc: Some("US-RC1-76-07839".to_string()), ..SongDetails::default() };
+    let err = update(&mut deps, "alice", None, None, Some(details)).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidMetadata { .. }));
+}
+
+#[test]
+fn a_new_music_uri_drops_the_old_content_hash() {
+    let mut deps = setup();
+    let msg = ExecuteMsg::UpdateTokenMetadata {
+        mint: MINT.to_string(),
+        name: None,
+        music_uri: None,
+        content_hash: Some(HexBinary::from(vec![7u8; 32])),
+        details: None,
+    };
+    run(&mut deps, "alice", &[], msg).unwrap();
+    assert!(metadata(&deps).content_hash.is_some());
+    
+    update(&mut deps, "alice", None, Some(NEW_URI), None).unwrap();
+    assert_eq!(metadata(&deps).content_hash, None);
+    let entries = history(&deps).entries;
+    let fields: Vec<&str> = entries[2].changes.iter().map(|change| change.field.as_str()).collect();
+    assert_eq!(fields, ["music_uri", "content_hash"]);
+}
+
+#[test]
+fn empty_values_clear_optional_details() {
+    let mut deps = setup();
+    let details = SongDetails {
+        artist: Some("Alice".to_string()),
+        genre: Some("Jazz".to_string()),
+        duration_seconds: Some(180),
+        links: Some(vec!["https://example.com".to_string()]),
+        ..SongDetails::default()
+    };
+    update(&mut deps, "alice", None, None, Some(details)).unwrap();
+    
+    // Unset fields are kept, empty ones are removed
+    let clear = SongDetails {
+        genre: Some(String::new()),
+        duration_seconds: Some(0),
+        links: Some(vec![]),
+        ..SongDetails::default()
+    };
+    update(&mut deps, "alice", None, None, Some(clear)).unwrap();
+    let expected = SongDetails { artist: Some("Alice".to_string()), ..SongDetails::default() };
+    assert_eq!(metadata(&deps).details, expected);
+    
+    let entries = history(&deps).entries;
+    assert_eq!(entries[2].changes, vec![
+        change("genre", Some("Jazz"), None),
+        change("duration_seconds", Some("180"), None),
+        change("links", Some("https://example.com"), None),
+    ]);
+}
+
+#[test]
+fn frozen_metadata_cannot_change() {
+    let mut deps = setup();
+    
+    let err = run(&mut deps, "bob", &[], ExecuteMsg::FreezeMetadata { mint: MINT.to_string() }).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    run(&mut deps, "alice", &[], ExecuteMsg::FreezeMetadata { mint: MINT.to_string() }).unwrap();
+    assert!(metadata(&deps).frozen);
+    
+    let err = update(&mut deps, "alice", Some("Renamed"), None, None).unwrap_err();
+    assert!(matches!(err, ContractError::MetadataFrozen {}));
+    let err = run(&mut deps, "alice", &[], ExecuteMsg::FreezeMetadata { mint: MINT.to_string() }).unwrap_err();
+    assert!(matches!(err, ContractError::MetadataFrozen {}));
+    
+    let entries = history(&deps).entries;
+    assert_eq!((entries.len(), entries[1].action.as_str()), (2, "freeze"));
+}
@@ -0,0 +1,143 @@
+mod common;
+
+use cosmwasm_std::testing::{mock_dependencies, mock_env};
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
+use serde::{Deserialize, Serialize};
+use hashmelody::{
+    contract::migrate,
+    error::ContractError,
+    msg::{ListTokensResponse, MigrateMsg, PlatformConfigResponse, QueryMsg, TokenMetadataResponse, TokenStatusResponse},
+    state::{TokenStatus, TokenVault, TOKEN_VAULTS},
+    utils::DEFAULT_UNBONDING_PERIOD
+};
+use common::{query_as, TestDeps};
+
+// Config and metadata as the first release stored them
+#[derive(Serialize, Deserialize)]
+struct LegacyConfig {
+    platform_wallet: Addr,
+    oracle_authority: Addr,
+    authority: Addr,
+}
+
+#[derive(Serialize, Deserialize)]
+struct LegacyTokenMetadata {
+    name: String,
+    music_uri: String,
+    id: u64,
+    mint: String,
+}
+
+const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+const LEGACY_METADATA: Map<&[u8], LegacyTokenMetadata> = Map::new("token_metadata");
+
+fn save_legacy_token(deps: &mut TestDeps, mint: &str, id: u64, name: &str) {
+    let metadata = LegacyTokenMetadata {
+        name: name.to_string(),
+        music_uri: "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_string(),
+        id,
+        mint: mint.to_string(),
+    };
+    LEGACY_METADATA.save(deps.as_mut().storage, mint.as_bytes(), &metadata).unwrap();
+}
+
+fn legacy_deployment() -> TestDeps {
+    let mut deps = mock_dependencies();
+    let config = LegacyConfig {
+        platform_wallet: Addr::unchecked("platform"),
+        oracle_authority: Addr::unchecked("oracle"),
+        authority: Addr::unchecked("admin"),
+    };
+    LEGACY_CONFIG.save(deps.as_mut().storage, &config).unwrap();
+    
+    save_legacy_token(&mut deps, "factory/alice/song", 1, "Song");
+    save_legacy_token(&mut deps, "factory/alice/demo", 2, "Demo");
+    let vault = TokenVault {
+        mint: "factory/alice/song".to_string(),
+        vault_account: Some(Addr::unchecked("vault")),
+        inj_vault_wallet: Addr::unchecked("vault"),
+        liquidity_threshold: Uint128::new(10_000_000_000),
+        total_collected: Uint128::zero(),
+        dex_pool: None,
+        native_collected: None,
+    };
+    TOKEN_VAULTS.save(deps.as_mut().storage, b"factory/alice/song", &vault).unwrap();
+    
+    deps
+}
+
+fn status(deps: &TestDeps, mint: &str) -> TokenStatus {
+    query_as::<TokenStatusResponse>(deps, QueryMsg::GetTokenStatus { mint: mint.to_string() }).status
+}
+
+#[test]
+fn legacy_config_reads_with_defaults() {
+    let mut deps = legacy_deployment();
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    
+    let config: PlatformConfigResponse = query_as(&deps, QueryMsg::GetPlatformConfig {});
+    assert_eq!(config.authority, "admin");
+    assert_eq!(config.referral_share_bps, 0);
+    assert_eq!(config.unbonding_period, DEFAULT_UNBONDING_PERIOD);
+    assert!(config.launch_fee.is_zero());
+    assert_eq!(config.moderator, None);
+}
+
+#[test]
+fn migrate_fills_creator_and_indexes() {
+    let mut deps = legacy_deployment();
+    let res = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    assert_eq!(common::attr(&res, "tokens"), "2");
+    
+    let song: TokenMetadataResponse = query_as(&deps, QueryMsg::GetTokenById { id: 1 });
+    assert_eq!(song.mint, "factory/alice/song");
+    assert_eq!(song.creator, "alice");
+    
+    let by_creator: ListTokensResponse = query_as(&deps, QueryMsg::ListTokensByCreator {
+        creator: "alice".to_string(),
+        start_after: None,
+        limit: None,
+    });
+    assert_eq!(by_creator.tokens.len(), 2);
+    
+    let by_name: ListTokensResponse = query_as(&deps, QueryMsg::SearchTokensByNamePrefix {
+        prefix: "dem".to_string(),
+        start_after: None,
+        limit: None,
+    });
+    assert_eq!(by_name.tokens[0].metadata.mint, "factory/alice/demo");
+}
+
+#[test]
+fn migrate_infers_statuses_from_vaults() {
+    let mut deps = legacy_deployment();
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    
+    assert_eq!(status(&deps, "factory/alice/song"), TokenStatus::Live);
+    assert_eq!(status(&deps, "factory/alice/demo"), TokenStatus::Draft);
+}
+
+#[test]
+fn migrate_can_run_twice() {
+    let mut deps = legacy_deployment();
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+    
+    let by_creator: ListTokensResponse = query_as(&deps, QueryMsg::ListTokensByCreator {
+        creator: "alice".to_string(),
+        start_after: None,
+        limit: None,
+    });
+    assert_eq!(by_creator.tokens.len(), 2);
+    assert_eq!(status(&deps, "factory/alice/song"), TokenStatus::Live);
+}
+
+#[test]
+fn migrate_rejects_duplicate_ids() {
+    let mut deps = legacy_deployment();
+    save_legacy_token(&mut deps, "factory/bob/cover", 1, "Cover");
+    
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    assert!(matches!(err, ContractError::TokenIdTaken { id: 1 }));
+}
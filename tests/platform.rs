@@ -0,0 +1,76 @@
+mod common;
+
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use hashmelody::{
+    contract::instantiate,
+    error::ContractError,
+    msg::{ExecuteMsg, InstantiateMsg, PlatformConfigResponse, QueryMsg}
+};
+use common::{query_as, run, setup, TestDeps};
+
+fn instantiate_with(referral: u16, holder: u16, staker: u16) -> Result<TestDeps, ContractError> {
+    let mut deps = mock_dependencies();
+    let msg = InstantiateMsg {
+        platform_wallet: "platform".to_string(),
+        oracle_authority: "oracle".to_string(),
+        referral_share_bps: Some(referral),
+        holder_reward_bps: Some(holder),
+        staker_reward_bps: Some(staker),
+        unbonding_period: None,
+        launch_fee: None,
+        moderator: None,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("admin", &[]), msg)?;
+    Ok(deps)
+}
+
+fn update_shares(deps: &mut TestDeps, sender: &str, holder: Option<u16>, staker: Option<u16>) -> Result<(), ContractError> {
+    let msg = ExecuteMsg::UpdatePlatform {
+        new_platform_wallet: "platform".to_string(),
+        new_oracle_authority: None,
+        new_referral_share_bps: None,
+        new_holder_reward_bps: holder,
+        new_staker_reward_bps: staker,
+        new_unbonding_period: None,
+        new_launch_fee: None,
+    };
+    run(deps, sender, &[], msg).map(|_| ())
+}
+
+#[test]
+fn fee_shares_may_not_exceed_the_platform_fee() {
+    assert!(instantiate_with(5_000, 2_500, 2_500).is_ok());
+    
+    let err = instantiate_with(5_000, 2_500, 2_501).err().unwrap();
+    assert!(matches!(err, ContractError::InvalidFeeShares {}));
+    let err = instantiate_with(10_001, 0, 0).err().unwrap();
+    assert!(matches!(err, ContractError::InvalidReferralShare {}));
+}
+
+#[test]
+fn oversized_fee_shares_are_rejected_without_overflow() {
+    // Each share fits in a u16, but their sum does not
+    let err = instantiate_with(0, 40_000, 40_000).err().unwrap();
+    assert!(matches!(err, ContractError::InvalidFeeShares {}));
+    let err = instantiate_with(0, u16::MAX, u16::MAX).err().unwrap();
+    assert!(matches!(err, ContractError::InvalidFeeShares {}));
+    
+    let mut deps = setup();
+    let err = update_shares(&mut deps, "admin", Some(u16::MAX), Some(u16::MAX)).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidFeeShares {}));
+}
+
+#[test]
+fn updated_shares_are_checked_against_the_stored_ones() {
+    let mut deps = setup();
+    
+    // The referral share is 5_000 and the staker share 2_000
+    let err = update_shares(&mut deps, "admin", Some(3_001), None).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidFeeShares {}));
+    let err = update_shares(&mut deps, "bob", Some(3_000), None).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    
+    update_shares(&mut deps, "admin", Some(3_000), Some(2_000)).unwrap();
+    let config: PlatformConfigResponse = query_as(&deps, QueryMsg::GetPlatformConfig {});
+    assert_eq!((config.holder_reward_bps, config.staker_reward_bps), (3_000, 2_000));
+}
@@ -0,0 +1,152 @@
+mod common;
+
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{coins, from_json, Addr, HexBinary, Response, Uint128};
+use sha2::{Digest, Sha256};
+use hashmelody::{
+    contract::query,
+    error::ContractError,
+    msg::{ExecuteMsg, PresaleAllocationResponse, PresaleParams, QueryMsg, SimulatePurchaseResponse, TokenStatusResponse},
+    state::TokenStatus,
+    utils::address_leaf
+};
+use common::{at, attr, query_as, run, run_at, setup, TestDeps, MINT, PRICE};
+
+// The presale opens at the mock block and the launch starts this many seconds later
+const START: u64 = 100;
+
+fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    Sha256::new().chain_update(first).chain_update(second).finalize().into()
+}
+
+// Merkle tree over bob, carl, dan and erin: the root and each buyer's proof
+fn allowlist_tree() -> (HexBinary, impl Fn(&str) -> Vec<HexBinary>) {
+    let names = ["bob", "carl", "dan", "erin"];
+    let leaves: Vec<[u8; 32]> = names.iter().map(|name| address_leaf(&Addr::unchecked(*name))).collect();
+    let pairs = [hash_pair(leaves[0], leaves[1]), hash_pair(leaves[2], leaves[3])];
+    let root = HexBinary::from(hash_pair(pairs[0], pairs[1]).to_vec());
+    
+    let proof = move |name: &str| {
+        let i = names.iter().position(|n| *n == name).unwrap();
+        vec![HexBinary::from(leaves[i ^ 1].to_vec()), HexBinary::from(pairs[1 - i / 2].to_vec())]
+    };
+    (root, proof)
+}
+
+fn presale_msg(merkle_root: HexBinary, duration: u64, discount_bps: u16) -> ExecuteMsg {
+    ExecuteMsg::SetLaunchConfig {
+        mint: MINT.to_string(),
+        start_time: mock_env().block.time.seconds() + START,
+        allowlist_duration: None,
+        protection_duration: 0,
+        max_per_address: None,
+        max_per_tx: None,
+        presale: Some(PresaleParams { merkle_root, duration, discount_bps, max_per_address: Uint128::new(3) }),
+    }
+}
+
+// A 20% presale discount with a cap of 3 tokens per address
+fn presale_deps() -> TestDeps {
+    let mut deps = setup();
+    let (root, _) = allowlist_tree();
+    run(&mut deps, "alice", &[], presale_msg(root, START, 2_000)).unwrap();
+    deps
+}
+
+fn buy_at(deps: &mut TestDeps, seconds: u64, buyer: &str, amount: u128, proof: Option<Vec<HexBinary>>) -> Result<Response, ContractError> {
+    let msg = ExecuteMsg::PurchaseToken {
+        mint: MINT.to_string(),
+        amount_tokens: Uint128::new(amount),
+        referrer: None,
+        proof,
+    };
+    run_at(deps, at(seconds), buyer, &coins(100 * PRICE, "inj"), msg)
+}
+
+fn simulate_at(deps: &TestDeps, seconds: u64) -> SimulatePurchaseResponse {
+    let msg = QueryMsg::SimulatePurchase { mint: MINT.to_string(), amount_tokens: Uint128::one() };
+    from_json(query(deps.as_ref(), at(seconds), msg).unwrap()).unwrap()
+}
+
+#[test]
+fn presale_settings_are_validated() {
+    let mut deps = setup();
+    let (root, _) = allowlist_tree();
+    
+    // A short root, a window reaching back before time zero, an empty window and a discount over 100%
+    let short_root = HexBinary::from(vec![0u8; 31]);
+    let invalid = [
+        presale_msg(short_root, START, 2_000),
+        presale_msg(root.clone(), mock_env().block.time.seconds() + START + 1, 2_000),
+        presale_msg(root.clone(), 0, 2_000),
+        presale_msg(root, START, 10_001),
+    ];
+    for msg in invalid {
+        let err = run(&mut deps, "alice", &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidLaunchConfig {}));
+    }
+}
+
+#[test]
+fn presale_moves_the_token_into_presale_until_the_start() {
+    let deps = presale_deps();
+    
+    let status: TokenStatusResponse = query_as(&deps, QueryMsg::GetTokenStatus { mint: MINT.to_string() });
+    assert_eq!(status.status, TokenStatus::Presale);
+    
+    // Quotes show the discounted presale price, then the public price from the start
+    let quote = simulate_at(&deps, 0);
+    assert_eq!((quote.status, quote.presale, quote.price_per_token), (TokenStatus::Presale, true, Uint128::new(PRICE * 8 / 10)));
+    let quote = simulate_at(&deps, START);
+    assert_eq!((quote.status, quote.presale, quote.price_per_token), (TokenStatus::Live, false, Uint128::new(PRICE)));
+}
+
+#[test]
+fn presale_needs_a_valid_proof() {
+    let mut deps = presale_deps();
+    let (_, proof) = allowlist_tree();
+    
+    let err = buy_at(&mut deps, 0, "bob", 1, None).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidMerkleProof {}));
+    
+    // Another buyer's proof, or a proof for someone off the list, fails
+    let err = buy_at(&mut deps, 0, "bob", 1, Some(proof("dan"))).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidMerkleProof {}));
+    let err = buy_at(&mut deps, 0, "frank", 1, Some(proof("carl"))).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidMerkleProof {}));
+    
+    for buyer in ["bob", "carl", "dan", "erin"] {
+        buy_at(&mut deps, 0, buyer, 1, Some(proof(buyer))).unwrap();
+    }
+}
+
+#[test]
+fn presale_buyers_pay_the_discounted_price_up_to_their_cap() {
+    let mut deps = presale_deps();
+    let (_, proof) = allowlist_tree();
+    
+    let res = buy_at(&mut deps, 0, "bob", 2, Some(proof("bob"))).unwrap();
+    assert_eq!(attr(&res, "total_cost"), (2 * PRICE * 8 / 10).to_string());
+    assert_eq!(attr(&res, "presale"), "true");
+    
+    let err = buy_at(&mut deps, 10, "bob", 2, Some(proof("bob"))).unwrap_err();
+    assert!(matches!(err, ContractError::ExceedsPresaleCap { max } if max == Uint128::new(3)));
+    
+    let msg = QueryMsg::GetPresaleAllocation { mint: MINT.to_string(), address: "bob".to_string() };
+    let allocation: PresaleAllocationResponse = query_as(&deps, msg);
+    assert_eq!((allocation.purchased, allocation.remaining), (Uint128::new(2), Uint128::one()));
+}
+
+#[test]
+fn public_sale_follows_the_presale() {
+    let mut deps = presale_deps();
+    let (_, proof) = allowlist_tree();
+    buy_at(&mut deps, 0, "bob", 3, Some(proof("bob"))).unwrap();
+    
+    // Anyone buys at the full price once the launch starts, past the presale cap
+    let res = buy_at(&mut deps, START, "bob", 2, None).unwrap();
+    assert_eq!(attr(&res, "presale"), "false");
+    assert_eq!(attr(&res, "status"), "live");
+    buy_at(&mut deps, START, "frank", 1, None).unwrap();
+}
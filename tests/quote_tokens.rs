@@ -0,0 +1,163 @@
+mod common;
+
+use cosmwasm_std::{coins, from_json, to_json_binary, Coin, CosmosMsg, Decimal, Response, Uint128, WasmMsg};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
+use hashmelody::{
+    error::ContractError,
+    msg::{ClaimableResponse, ExecuteMsg, QueryMsg, QuoteTokensResponse, ReceiveMsg}
+};
+use common::{attr, bank_send, query_as, run, setup, TestDeps, MINT, PRICE};
+
+// CW20 stablecoin contract
+const USD: &str = "usd";
+
+// Accept USD at two units per INJ unit
+fn add_usd(deps: &mut TestDeps) {
+    let msg = ExecuteMsg::AddQuoteToken { token: USD.to_string(), inj_rate: Decimal::from_ratio(2u128, 1u128) };
+    run(deps, "admin", &[], msg).unwrap();
+}
+
+// `token` forwards `amount` from `buyer` with a purchase payload
+fn pay(deps: &mut TestDeps, token: &str, buyer: &str, amount: u128, min_tokens_out: u128) -> Result<Response, ContractError> {
+    let payload = ReceiveMsg::Purchase {
+        mint: MINT.to_string(),
+        min_tokens_out: Uint128::new(min_tokens_out),
+        referrer: None,
+        proof: None,
+    };
+    let msg = ExecuteMsg::Receive(Cw20ReceiveMsg {
+        sender: buyer.to_string(),
+        amount: Uint128::new(amount),
+        msg: to_json_binary(&payload).unwrap(),
+    });
+    run(deps, token, &[], msg)
+}
+
+// CW20 transfers a response makes, as (token, recipient, amount)
+fn cw20_transfers(response: &Response) -> Vec<(String, String, Uint128)> {
+    response
+        .messages
+        .iter()
+        .map(|message| match &message.msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, msg, .. }) => match from_json(msg).unwrap() {
+                Cw20ExecuteMsg::Transfer { recipient, amount } => (contract_addr.clone(), recipient, amount),
+                other => panic!("unexpected cw20 message {:?}", other),
+            },
+            other => panic!("unexpected message {:?}", other),
+        })
+        .collect()
+}
+
+fn claimable(deps: &TestDeps, address: &str) -> Vec<Coin> {
+    query_as::<ClaimableResponse>(deps, QueryMsg::GetClaimable { address: address.to_string() }).balances
+}
+
+#[test]
+fn only_the_authority_manages_quote_tokens() {
+    let mut deps = setup();
+    let msg = ExecuteMsg::AddQuoteToken { token: USD.to_string(), inj_rate: Decimal::one() };
+    let err = run(&mut deps, "bob", &[], msg).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    
+    let msg = ExecuteMsg::AddQuoteToken { token: USD.to_string(), inj_rate: Decimal::zero() };
+    let err = run(&mut deps, "admin", &[], msg).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidAmount {}));
+    
+    add_usd(&mut deps);
+    let tokens: QuoteTokensResponse = query_as(&deps, QueryMsg::ListQuoteTokens {});
+    assert_eq!(tokens.tokens.len(), 1);
+    assert_eq!(tokens.tokens[0].inj_rate, Decimal::from_ratio(2u128, 1u128));
+    
+    let err = run(&mut deps, "bob", &[], ExecuteMsg::RemoveQuoteToken { token: USD.to_string() }).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+}
+
+#[test]
+fn cw20_purchase_buys_whole_tokens_and_refunds_the_rest() {
+    let mut deps = setup();
+    add_usd(&mut deps);
+    
+    // One token costs 2 * PRICE usd; 5.5 tokens' worth buys 2
+    let res = pay(&mut deps, USD, "bob", 5 * PRICE + PRICE / 2, 2).unwrap();
+    assert_eq!(attr(&res, "mint_amount"), "2");
+    assert_eq!(attr(&res, "payment_asset"), "cw20:usd");
+    assert_eq!(attr(&res, "refund"), (PRICE + PRICE / 2).to_string());
+    
+    // 7.5% of the cost stays for fees and royalty, the rest goes to the vault, then the refund
+    let transfers = cw20_transfers(&res);
+    assert_eq!(transfers.len(), 2);
+    assert_eq!(transfers[0].2, Uint128::new(4 * PRICE - 300_000_000));
+    assert_eq!(transfers[1], (USD.to_string(), "bob".to_string(), Uint128::new(PRICE + PRICE / 2)));
+    
+    // Fees accrue in the quote token; holders only share INJ fees
+    assert_eq!(attr(&res, "holder_reward"), "0");
+    assert_eq!(claimable(&deps, "platform"), coins(100_000_000, "cw20:usd"));
+    assert_eq!(claimable(&deps, "alice"), coins(200_000_000, "cw20:usd"));
+}
+
+#[test]
+fn cw20_fees_are_claimed_in_the_token() {
+    let mut deps = setup();
+    add_usd(&mut deps);
+    pay(&mut deps, USD, "bob", 2 * PRICE, 1).unwrap();
+    
+    let res = run(&mut deps, "alice", &[], ExecuteMsg::ClaimFees { denom: "cw20:usd".to_string() }).unwrap();
+    assert_eq!(cw20_transfers(&res), vec![(USD.to_string(), "alice".to_string(), Uint128::new(100_000_000))]);
+}
+
+#[test]
+fn cw20_purchase_respects_min_tokens_out() {
+    let mut deps = setup();
+    add_usd(&mut deps);
+    
+    let err = pay(&mut deps, USD, "bob", 3 * PRICE, 2).unwrap_err();
+    assert!(matches!(
+        err,
+        ContractError::SlippageExceeded { min_tokens_out, amount_tokens }
+            if min_tokens_out == Uint128::new(2) && amount_tokens == Uint128::one()
+    ));
+    
+    // Too little for a single token
+    let err = pay(&mut deps, USD, "bob", PRICE, 0).unwrap_err();
+    assert!(matches!(err, ContractError::SlippageExceeded { .. }));
+}
+
+#[test]
+fn only_accepted_tokens_can_pay() {
+    let mut deps = setup();
+    add_usd(&mut deps);
+    
+    let err = pay(&mut deps, "fake", "bob", 2 * PRICE, 1).unwrap_err();
+    assert!(matches!(err, ContractError::UnsupportedQuoteToken {}));
+    
+    // Removed tokens stop being accepted
+    run(&mut deps, "admin", &[], ExecuteMsg::RemoveQuoteToken { token: USD.to_string() }).unwrap();
+    let err = pay(&mut deps, USD, "bob", 2 * PRICE, 1).unwrap_err();
+    assert!(matches!(err, ContractError::UnsupportedQuoteToken {}));
+    let err = run(&mut deps, "admin", &[], ExecuteMsg::RemoveQuoteToken { token: USD.to_string() }).unwrap_err();
+    assert!(matches!(err, ContractError::UnsupportedQuoteToken {}));
+}
+
+#[test]
+fn native_purchase_refunds_surplus_inj() {
+    let mut deps = setup();
+    let purchase = ExecuteMsg::PurchaseToken {
+        mint: MINT.to_string(),
+        amount_tokens: Uint128::one(),
+        referrer: None,
+        proof: None,
+    };
+    
+    let err = run(&mut deps, "bob", &coins(PRICE - 1, "inj"), purchase.clone()).unwrap_err();
+    assert!(matches!(err, ContractError::InsufficientFunds {}));
+    
+    // Exact payment sends only the vault its share
+    let res = run(&mut deps, "bob", &coins(PRICE, "inj"), purchase.clone()).unwrap();
+    assert_ne!(bank_send(&res).0, "bob");
+    assert!(res.attributes.iter().all(|attr| attr.key != "refund"));
+    
+    // The second token costs the same with no views; the extra INJ comes back
+    let res = run(&mut deps, "carl", &coins(PRICE + 123, "inj"), purchase).unwrap();
+    assert_eq!(attr(&res, "refund"), "123");
+    assert_eq!(res.messages.len(), 2);
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use cosmwasm_std::testing::MOCK_CONTRACT_ADDR;
+use cosmwasm_std::{
+    coins, from_json, to_json_binary, ContractResult, CosmosMsg, Decimal, Response, SystemResult, Uint128, WasmMsg,
+    WasmQuery
+};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20ReceiveMsg};
+use hashmelody::{
+    error::ContractError,
+    msg::{ExecuteMsg, ReceiveMsg}
+};
+use common::{bank_send, buy, run, setup, TestDeps, MINT, PRICE};
+
+fn recover(deps: &mut TestDeps, sender: &str, asset: &str, amount: u128) -> Result<Response, ContractError> {
+    let msg = ExecuteMsg::RecoverTokens {
+        asset: asset.to_string(),
+        amount: Uint128::new(amount),
+        recipient: "treasury".to_string(),
+    };
+    run(deps, sender, &[], msg)
+}
+
+// Every CW20 contract reports `balance` for the contract
+fn set_cw20_balance(deps: &mut TestDeps, balance: u128) {
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { .. } => {
+            let response = BalanceResponse { balance: Uint128::new(balance) };
+            SystemResult::Ok(ContractResult::Ok(to_json_binary(&response).unwrap()))
+        },
+        other => panic!("unexpected query {:?}", other),
+    });
+}
+
+#[test]
+fn only_the_authority_recovers() {
+    let mut deps = setup();
+    deps.querier.update_balance(MOCK_CONTRACT_ADDR, coins(1_000, "inj"));
+    
+    let err = recover(&mut deps, "bob", "inj", 1_000).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    let err = recover(&mut deps, "admin", "inj", 0).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidAmount {}));
+}
+
+#[test]
+fn owed_inj_cannot_be_recovered() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 2, None).unwrap();
+    
+    // 150_000_000 of fees and royalty are owed to the platform, alice and holders; 1_000 is stray
+    deps.querier.update_balance(MOCK_CONTRACT_ADDR, coins(150_001_000, "inj"));
+    let err = recover(&mut deps, "admin", "inj", 1_001).unwrap_err();
+    assert!(matches!(err, ContractError::ExceedsRecoverable { available } if available == Uint128::new(1_000)));
+    
+    let res = recover(&mut deps, "admin", "inj", 1_000).unwrap();
+    assert_eq!(bank_send(&res), ("treasury".to_string(), coins(1_000, "inj")));
+}
+
+#[test]
+fn stray_cw20_tokens_can_be_recovered() {
+    let mut deps = setup();
+    run(&mut deps, "admin", &[], ExecuteMsg::AddQuoteToken { token: "usd".to_string(), inj_rate: Decimal::one() }).unwrap();
+    
+    // Buying 1 token in usd leaves 75_000_000 usd owed in fees and royalty
+    let payload = to_json_binary(&ReceiveMsg::Purchase {
+        mint: MINT.to_string(),
+        min_tokens_out: Uint128::one(),
+        referrer: None,
+        proof: None,
+    })
+    .unwrap();
+    let receive = Cw20ReceiveMsg { sender: "bob".to_string(), amount: Uint128::new(PRICE), msg: payload };
+    run(&mut deps, "usd", &[], ExecuteMsg::Receive(receive)).unwrap();
+    
+    set_cw20_balance(&mut deps, 75_000_500);
+    let err = recover(&mut deps, "admin", "cw20:usd", 501).unwrap_err();
+    assert!(matches!(err, ContractError::ExceedsRecoverable { available } if available == Uint128::new(500)));
+    
+    let res = recover(&mut deps, "admin", "cw20:usd", 500).unwrap();
+    match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Execute { contract_addr, msg, .. }) => {
+            assert_eq!(contract_addr, "usd");
+            assert_eq!(
+                from_json::<Cw20ExecuteMsg>(msg).unwrap(),
+                Cw20ExecuteMsg::Transfer { recipient: "treasury".to_string(), amount: Uint128::new(500) }
+            );
+        },
+        other => panic!("unexpected message {:?}", other),
+    }
+}
+
+#[test]
+fn unknown_receive_payloads_are_rejected() {
+    let mut deps = setup();
+    run(&mut deps, "admin", &[], ExecuteMsg::AddQuoteToken { token: "usd".to_string(), inj_rate: Decimal::one() }).unwrap();
+    
+    // The error reverts the transfer, so the tokens never reach the contract
+    for payload in [to_json_binary(&"deposit").unwrap(), to_json_binary(&()).unwrap()] {
+        let receive = Cw20ReceiveMsg { sender: "bob".to_string(), amount: Uint128::new(100), msg: payload };
+        let err = run(&mut deps, "usd", &[], ExecuteMsg::Receive(receive)).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidReceiveMsg {}));
+    }
+}
@@ -0,0 +1,63 @@
+mod common;
+
+use cosmwasm_std::{coins, Coin};
+use hashmelody::{
+    error::ContractError,
+    msg::{ClaimableResponse, QueryMsg, ReferralStatsResponse}
+};
+use common::{attr, buy, query_as, setup, TestDeps};
+
+fn claimable(deps: &TestDeps, address: &str) -> Vec<Coin> {
+    query_as::<ClaimableResponse>(deps, QueryMsg::GetClaimable { address: address.to_string() }).balances
+}
+
+fn referral_stats(deps: &TestDeps, address: &str) -> ReferralStatsResponse {
+    query_as(deps, QueryMsg::GetReferralStats { address: address.to_string() })
+}
+
+#[test]
+fn referrer_earns_share_of_platform_fee() {
+    let mut deps = setup();
+    let res = buy(&mut deps, "bob", 2, Some("carl")).unwrap();
+    
+    // Half of the 50_000_000 platform fee goes to the referrer, before the holder share
+    assert_eq!(attr(&res, "referral_reward"), "25000000");
+    assert_eq!(claimable(&deps, "carl"), coins(25_000_000, "inj"));
+    assert_eq!(claimable(&deps, "platform"), coins(15_000_000, "inj"));
+    
+    // The artist's royalty is unaffected
+    assert_eq!(claimable(&deps, "alice"), coins(100_000_000, "inj"));
+}
+
+#[test]
+fn referral_stats_accumulate() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 2, Some("carl")).unwrap();
+    buy(&mut deps, "dan", 1, Some("carl")).unwrap();
+    buy(&mut deps, "erin", 1, None).unwrap();
+    
+    let stats = referral_stats(&deps, "carl");
+    assert_eq!(stats.total_earned.u128(), 37_500_000);
+    assert_eq!(stats.referred_purchases, 2);
+    assert_eq!(claimable(&deps, "carl"), coins(37_500_000, "inj"));
+}
+
+#[test]
+fn no_referrer_leaves_stats_empty() {
+    let mut deps = setup();
+    let res = buy(&mut deps, "bob", 1, None).unwrap();
+    
+    assert_eq!(attr(&res, "referral_reward"), "0");
+    let stats = referral_stats(&deps, "bob");
+    assert!(stats.total_earned.is_zero());
+    assert_eq!(stats.referred_purchases, 0);
+}
+
+#[test]
+fn self_referral_is_rejected() {
+    let mut deps = setup();
+    let err = buy(&mut deps, "bob", 1, Some("bob")).unwrap_err();
+    
+    assert!(matches!(err, ContractError::SelfReferral {}));
+    assert!(claimable(&deps, "bob").is_empty());
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use hashmelody::{
+    error::ContractError,
+    msg::{ExecuteMsg, ListTokensResponse, QueryMsg, TokenMetadataResponse}
+};
+use common::{query_as, query_err, register_song, run, setup, TestDeps, MINT};
+
+fn mints(response: ListTokensResponse) -> Vec<String> {
+    response.tokens.into_iter().map(|token| token.metadata.mint).collect()
+}
+
+fn search(deps: &TestDeps, prefix: &str, start_after: Option<&str>, limit: Option<u32>) -> Vec<String> {
+    mints(query_as(deps, QueryMsg::SearchTokensByNamePrefix {
+        prefix: prefix.to_string(),
+        start_after: start_after.map(str::to_string),
+        limit,
+    }))
+}
+
+#[test]
+fn token_ids_are_unique() {
+    let mut deps = setup();
+    let msg = ExecuteMsg::InitializeTokenMetadata {
+        id: 1,
+        name: "Cover".to_string(),
+        music_uri: "https://example.com/cover.mp3".to_string(),
+        content_hash: None,
+        mint: "factory/bob/cover".to_string(),
+        creator: None,
+        royalty_bps: 0,
+        vesting: None,
+        mint_master_nft: None,
+        details: None,
+    };
+    
+    let err = run(&mut deps, "bob", &[], msg).unwrap_err();
+    assert!(matches!(err, ContractError::TokenIdTaken { id: 1 }));
+}
+
+#[test]
+fn tokens_are_found_by_id() {
+    let mut deps = setup();
+    register_song(&mut deps, "bob", "cover", 7, "Cover");
+    
+    let token: TokenMetadataResponse = query_as(&deps, QueryMsg::GetTokenById { id: 1 });
+    assert_eq!(token.mint, MINT);
+    let token: TokenMetadataResponse = query_as(&deps, QueryMsg::GetTokenById { id: 7 });
+    assert_eq!(token.mint, "factory/bob/cover");
+    
+    assert!(matches!(query_err(&deps, QueryMsg::GetTokenById { id: 8 }), ContractError::Std(_)));
+}
+
+#[test]
+fn tokens_are_listed_by_creator() {
+    let mut deps = setup();
+    register_song(&mut deps, "alice", "ballad", 2, "Ballad");
+    register_song(&mut deps, "bob", "cover", 3, "Cover");
+    
+    let page = |start_after: Option<&str>| -> Vec<String> {
+        mints(query_as(&deps, QueryMsg::ListTokensByCreator {
+            creator: "alice".to_string(),
+            start_after: start_after.map(str::to_string),
+            limit: Some(1),
+        }))
+    };
+    assert_eq!(page(None), vec!["factory/alice/ballad"]);
+    assert_eq!(page(Some("factory/alice/ballad")), vec![MINT]);
+    assert!(page(Some(MINT)).is_empty());
+}
+
+#[test]
+fn name_search_ignores_case_and_whitespace() {
+    let mut deps = setup();
+    register_song(&mut deps, "bob", "summer", 2, "Summer Nights");
+    register_song(&mut deps, "carl", "sunset", 3, "sunset drive");
+    
+    assert_eq!(search(&deps, "  SU", None, None), vec!["factory/bob/summer", "factory/carl/sunset"]);
+    assert_eq!(search(&deps, "summer n", None, None), vec!["factory/bob/summer"]);
+    assert_eq!(search(&deps, "song", None, None), vec![MINT]);
+    assert!(search(&deps, "x", None, None).is_empty());
+}
+
+#[test]
+fn name_search_pages_through_equal_names() {
+    let mut deps = setup();
+    register_song(&mut deps, "bob", "song", 2, "song");
+    register_song(&mut deps, "carl", "song", 3, "Song ");
+    
+    let first = search(&deps, "song", None, Some(2));
+    assert_eq!(first.len(), 2);
+    let rest = search(&deps, "song", Some(&first[1]), Some(2));
+    assert_eq!(rest.len(), 1);
+    assert!(!first.contains(&rest[0]));
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use cosmwasm_std::{coins, Uint128};
+use hashmelody::{
+    error::ContractError,
+    msg::{ClaimableResponse, ExecuteMsg, QueryMsg, RoyaltyTotalResponse},
+    utils::MAX_ROYALTY_BPS
+};
+use common::{buy, query_as, run, setup, TestDeps, MINT};
+
+fn song_msg(subdenom: &str, id: u64, creator: Option<&str>, royalty_bps: u16) -> ExecuteMsg {
+    ExecuteMsg::InitializeTokenMetadata {
+        id,
+        name: format!("Song {}", id),
+        music_uri: "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_string(),
+        content_hash: None,
+        mint: format!("factory/alice/{}", subdenom),
+        creator: creator.map(str::to_string),
+        royalty_bps,
+        vesting: None,
+        mint_master_nft: None,
+        details: None,
+    }
+}
+
+fn token_royalties(deps: &TestDeps, mint: &str) -> Uint128 {
+    query_as::<RoyaltyTotalResponse>(deps, QueryMsg::GetTokenRoyalties { mint: mint.to_string() }).total
+}
+
+fn creator_royalties(deps: &TestDeps, creator: &str) -> Uint128 {
+    query_as::<RoyaltyTotalResponse>(deps, QueryMsg::GetCreatorRoyalties { creator: creator.to_string() }).total
+}
+
+#[test]
+fn royalty_is_capped() {
+    let mut deps = setup();
+    
+    let err = run(&mut deps, "alice", &[], song_msg("loud", 2, None, MAX_ROYALTY_BPS + 1)).unwrap_err();
+    assert!(matches!(err, ContractError::RoyaltyTooHigh { max_bps: MAX_ROYALTY_BPS }));
+    
+    // The cap itself and no royalty at all are both allowed
+    run(&mut deps, "alice", &[], song_msg("max", 2, None, MAX_ROYALTY_BPS)).unwrap();
+    run(&mut deps, "alice", &[], song_msg("free", 3, None, 0)).unwrap();
+}
+
+#[test]
+fn only_the_denom_creator_registers_a_song() {
+    let mut deps = setup();
+    
+    // Bob cannot register alice's denom, even naming her as the royalty recipient
+    let err = run(&mut deps, "bob", &[], song_msg("other", 2, Some("alice"), 500)).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+}
+
+#[test]
+fn royalty_goes_to_the_named_recipient() {
+    let mut deps = setup();
+    let mint = "factory/alice/band".to_string();
+    run(&mut deps, "alice", &[], song_msg("band", 2, Some("band"), 1_000)).unwrap();
+    run(&mut deps, "alice", &[], ExecuteMsg::InitializeTokenOracle { mint: mint.clone() }).unwrap();
+    run(&mut deps, "alice", &[], ExecuteMsg::SetupVaultAccount { mint: mint.clone() }).unwrap();
+    
+    let msg = ExecuteMsg::PurchaseToken {
+        mint: mint.clone(),
+        amount_tokens: Uint128::new(2),
+        referrer: None,
+        proof: None,
+    };
+    run(&mut deps, "bob", &coins(2_000_000_000, "inj"), msg).unwrap();
+    
+    // 10% of 2 INJ accrues to the band, not to the denom creator
+    let band: ClaimableResponse = query_as(&deps, QueryMsg::GetClaimable { address: "band".to_string() });
+    assert_eq!(band.balances, coins(200_000_000, "inj"));
+    let alice: ClaimableResponse = query_as(&deps, QueryMsg::GetClaimable { address: "alice".to_string() });
+    assert!(alice.balances.is_empty());
+    
+    assert_eq!(token_royalties(&deps, &mint), Uint128::new(200_000_000));
+    assert_eq!(creator_royalties(&deps, "band"), Uint128::new(200_000_000));
+}
+
+#[test]
+fn royalty_totals_add_up_per_token_and_creator() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 2, None).unwrap();
+    buy(&mut deps, "carl", 1, None).unwrap();
+    
+    assert_eq!(token_royalties(&deps, MINT), Uint128::new(150_000_000));
+    assert_eq!(creator_royalties(&deps, "alice"), Uint128::new(150_000_000));
+    assert_eq!(creator_royalties(&deps, "bob"), Uint128::zero());
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use cosmwasm_std::Uint128;
+use hashmelody::{
+    error::ContractError,
+    msg::{QueryMsg, SimulatePurchaseResponse},
+    state::TokenStatus
+};
+use common::{attr, buy, query_as, query_err, setup, TestDeps, MINT, PRICE};
+
+fn simulate(deps: &TestDeps, amount: u128) -> SimulatePurchaseResponse {
+    query_as(deps, QueryMsg::SimulatePurchase { mint: MINT.to_string(), amount_tokens: Uint128::new(amount) })
+}
+
+fn simulate_exact_in(deps: &TestDeps, funds: u128) -> SimulatePurchaseResponse {
+    query_as(deps, QueryMsg::SimulatePurchaseExactIn { mint: MINT.to_string(), funds: Uint128::new(funds) })
+}
+
+#[test]
+fn simulation_breaks_down_the_cost() {
+    let deps = setup();
+    
+    assert_eq!(simulate(&deps, 2), SimulatePurchaseResponse {
+        amount_tokens: Uint128::new(2),
+        price_per_token: Uint128::new(PRICE),
+        total_cost: Uint128::new(2 * PRICE),
+        platform_fee: Uint128::new(50_000_000),
+        royalty: Uint128::new(100_000_000),
+        vault_amount: Uint128::new(1_850_000_000),
+        resulting_supply: Uint128::new(2),
+        crosses_liquidity_threshold: false,
+        status: TokenStatus::Live,
+        presale: false,
+    });
+}
+
+#[test]
+fn simulation_matches_the_purchase() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 1, None).unwrap();
+    
+    let quote = simulate(&deps, 3);
+    let res = buy(&mut deps, "carl", 3, None).unwrap();
+    assert_eq!(attr(&res, "total_cost"), quote.total_cost.to_string());
+    assert_eq!(attr(&res, "platform_fee"), quote.platform_fee.to_string());
+    assert_eq!(attr(&res, "royalty"), quote.royalty.to_string());
+    assert_eq!(quote.resulting_supply.u128(), 4);
+}
+
+#[test]
+fn exact_in_buys_whole_tokens() {
+    let deps = setup();
+    
+    let quote = simulate_exact_in(&deps, 2 * PRICE + PRICE / 2);
+    assert_eq!(quote.amount_tokens.u128(), 2);
+    assert_eq!(quote.total_cost.u128(), 2 * PRICE);
+    assert_eq!(quote, simulate(&deps, 2));
+    
+    let quote = simulate_exact_in(&deps, PRICE - 1);
+    assert!(quote.amount_tokens.is_zero());
+    assert!(quote.total_cost.is_zero());
+}
+
+#[test]
+fn simulation_flags_crossing_the_liquidity_threshold() {
+    let mut deps = setup();
+    buy(&mut deps, "bob", 10, None).unwrap();
+    
+    // 10 tokens put 9_250_000_000 in the vault; the threshold is 10 INJ
+    assert!(!simulate(&deps, 0).crosses_liquidity_threshold);
+    assert!(simulate(&deps, 1).crosses_liquidity_threshold);
+    assert!(simulate_exact_in(&deps, 5 * PRICE).crosses_liquidity_threshold);
+}
+
+#[test]
+fn simulation_of_unknown_token_fails() {
+    let deps = setup();
+    let msg = QueryMsg::SimulatePurchase { mint: "factory/alice/other".to_string(), amount_tokens: Uint128::one() };
+    
+    assert!(matches!(query_err(&deps, msg), ContractError::TokenNotFound { .. }));
+}
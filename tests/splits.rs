@@ -0,0 +1,95 @@
+mod common;
+
+use cosmwasm_std::{coins, Addr, Uint128};
+use hashmelody::{
+    error::ContractError,
+    msg::{ClaimableResponse, ExecuteMsg, QueryMsg, RevenueSplitResponse, RoyaltyTotalResponse, SplitRecipientMsg},
+    state::SplitRecipient
+};
+use common::{buy, query_as, run, setup, TestDeps, MINT};
+
+fn recipients(weights: &[(&str, u16)]) -> Vec<SplitRecipientMsg> {
+    weights
+        .iter()
+        .map(|(address, weight_bps)| SplitRecipientMsg { address: address.to_string(), weight_bps: *weight_bps })
+        .collect()
+}
+
+fn set_split(deps: &mut TestDeps, sender: &str, weights: &[(&str, u16)]) -> Result<(), ContractError> {
+    let msg = ExecuteMsg::SetRevenueSplit { mint: MINT.to_string(), recipients: recipients(weights) };
+    run(deps, sender, &[], msg).map(|_| ())
+}
+
+#[test]
+fn invalid_splits_are_rejected() {
+    let mut deps = setup();
+    let eleven: Vec<(String, u16)> = (0..11).map(|i| (format!("artist{}", i), 1_000)).collect();
+    let eleven: Vec<(&str, u16)> = eleven.iter().map(|(a, w)| (a.as_str(), *w)).collect();
+    
+    let invalid: [&[(&str, u16)]; 6] = [
+        // No recipients
+        &[],
+        // Weights short of, or over, 100%
+        &[("alice", 6_000), ("producer", 3_000)],
+        &[("alice", 7_000), ("producer", 4_000)],
+        // A zero weight
+        &[("alice", 10_000), ("producer", 0)],
+        // The same address twice
+        &[("alice", 5_000), ("alice", 5_000)],
+        // Too many recipients
+        &eleven,
+    ];
+    for weights in invalid {
+        let err = set_split(&mut deps, "alice", weights).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidSplit {}), "{:?}", weights);
+    }
+    
+    // Nothing was stored, so the creator still takes the whole royalty
+    let split: RevenueSplitResponse = query_as(&deps, QueryMsg::GetRevenueSplit { mint: MINT.to_string() });
+    assert!(split.recipients.is_empty());
+}
+
+#[test]
+fn only_the_rights_holder_sets_a_split() {
+    let mut deps = setup();
+    
+    let err = set_split(&mut deps, "bob", &[("bob", 10_000)]).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+}
+
+#[test]
+fn split_can_be_replaced_until_the_first_purchase() {
+    let mut deps = setup();
+    set_split(&mut deps, "alice", &[("alice", 5_000), ("producer", 5_000)]).unwrap();
+    set_split(&mut deps, "alice", &[("alice", 8_000), ("mixer", 2_000)]).unwrap();
+    
+    let split: RevenueSplitResponse = query_as(&deps, QueryMsg::GetRevenueSplit { mint: MINT.to_string() });
+    assert_eq!(split.recipients, vec![
+        SplitRecipient { address: Addr::unchecked("alice"), weight_bps: 8_000 },
+        SplitRecipient { address: Addr::unchecked("mixer"), weight_bps: 2_000 },
+    ]);
+    
+    // Once bought, the split is locked
+    buy(&mut deps, "bob", 1, None).unwrap();
+    let err = set_split(&mut deps, "alice", &[("alice", 10_000)]).unwrap_err();
+    assert!(matches!(err, ContractError::SplitLocked {}));
+}
+
+#[test]
+fn royalty_is_divided_by_weight() {
+    let mut deps = setup();
+    set_split(&mut deps, "alice", &[("alice", 3_333), ("producer", 3_333), ("mixer", 3_334)]).unwrap();
+    
+    // The 50_000_000 royalty on one token
+    buy(&mut deps, "bob", 1, None).unwrap();
+    let claimable = |address: &str| {
+        query_as::<ClaimableResponse>(&deps, QueryMsg::GetClaimable { address: address.to_string() }).balances
+    };
+    assert_eq!(claimable("alice"), coins(16_665_000, "inj"));
+    assert_eq!(claimable("producer"), coins(16_665_000, "inj"));
+    assert_eq!(claimable("mixer"), coins(16_670_000, "inj"));
+    
+    // Royalty totals follow the split too
+    let msg = QueryMsg::GetCreatorRoyalties { creator: "producer".to_string() };
+    assert_eq!(query_as::<RoyaltyTotalResponse>(&deps, msg).total, Uint128::new(16_665_000));
+}
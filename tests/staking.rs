@@ -0,0 +1,109 @@
+mod common;
+
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{coins, Uint128};
+use hashmelody::{
+    error::ContractError,
+    msg::{ExecuteMsg, QueryMsg, StakedPositionResponse, StakingPoolResponse},
+    state::Unbonding
+};
+use common::{at, attr, bank_send, buy, query_as, run, run_at, setup, TestDeps, MINT};
+
+fn position(deps: &TestDeps, address: &str) -> StakedPositionResponse {
+    query_as(deps, QueryMsg::GetStakedPosition { mint: MINT.to_string(), address: address.to_string() })
+}
+
+fn staking_pool(deps: &TestDeps) -> StakingPoolResponse {
+    query_as(deps, QueryMsg::GetStakingPool { mint: MINT.to_string() })
+}
+
+fn stake(deps: &mut TestDeps, staker: &str, amount: u128) {
+    run(deps, staker, &coins(amount, MINT), ExecuteMsg::Stake { mint: MINT.to_string() }).unwrap();
+}
+
+#[test]
+fn stake_requires_song_tokens() {
+    let mut deps = setup();
+    let err = run(&mut deps, "bob", &coins(5, "inj"), ExecuteMsg::Stake { mint: MINT.to_string() }).unwrap_err();
+    
+    assert!(matches!(err, ContractError::InvalidStakeFunds { .. }));
+}
+
+#[test]
+fn stakers_share_platform_fees() {
+    let mut deps = setup();
+    
+    // With nothing staked the platform keeps the staker share
+    let res = buy(&mut deps, "carl", 1, None).unwrap();
+    assert_eq!(attr(&res, "staker_reward"), "0");
+    
+    stake(&mut deps, "bob", 6);
+    stake(&mut deps, "dan", 4);
+    let res = buy(&mut deps, "carl", 1, None).unwrap();
+    assert_eq!(attr(&res, "staker_reward"), "5000000");
+    
+    assert_eq!(position(&deps, "bob").claimable_rewards.u128(), 3_000_000);
+    assert_eq!(position(&deps, "dan").claimable_rewards.u128(), 2_000_000);
+}
+
+#[test]
+fn unstake_queues_tokens_until_unbonded() {
+    let mut deps = setup();
+    stake(&mut deps, "bob", 10);
+    
+    let err = run(&mut deps, "bob", &[], ExecuteMsg::Unstake { mint: MINT.to_string(), amount: Uint128::new(11) }).unwrap_err();
+    assert!(matches!(err, ContractError::InsufficientStake { staked } if staked.u128() == 10));
+    
+    run(&mut deps, "bob", &[], ExecuteMsg::Unstake { mint: MINT.to_string(), amount: Uint128::new(4) }).unwrap();
+    let position = position(&deps, "bob");
+    assert_eq!(position.staked.u128(), 6);
+    assert_eq!(position.unbonding, vec![Unbonding {
+        amount: Uint128::new(4),
+        release_time: mock_env().block.time.seconds() + 100,
+    }]);
+    
+    let pool = staking_pool(&deps).pool;
+    assert_eq!(pool.total_staked.u128(), 6);
+    assert_eq!(pool.total_unbonding.u128(), 4);
+}
+
+#[test]
+fn unbonding_tokens_earn_nothing() {
+    let mut deps = setup();
+    stake(&mut deps, "bob", 5);
+    stake(&mut deps, "dan", 5);
+    run(&mut deps, "dan", &[], ExecuteMsg::Unstake { mint: MINT.to_string(), amount: Uint128::new(5) }).unwrap();
+    buy(&mut deps, "carl", 1, None).unwrap();
+    
+    assert_eq!(position(&deps, "bob").claimable_rewards.u128(), 5_000_000);
+    assert!(position(&deps, "dan").claimable_rewards.is_zero());
+}
+
+#[test]
+fn withdraw_waits_for_unbonding_period() {
+    let mut deps = setup();
+    stake(&mut deps, "bob", 10);
+    run(&mut deps, "bob", &[], ExecuteMsg::Unstake { mint: MINT.to_string(), amount: Uint128::new(4) }).unwrap();
+    
+    let err = run_at(&mut deps, at(50), "bob", &[], ExecuteMsg::WithdrawUnbonded { mint: MINT.to_string() }).unwrap_err();
+    assert!(matches!(err, ContractError::NothingToClaim {}));
+    
+    let res = run_at(&mut deps, at(100), "bob", &[], ExecuteMsg::WithdrawUnbonded { mint: MINT.to_string() }).unwrap();
+    assert_eq!(bank_send(&res), ("bob".to_string(), coins(4, MINT)));
+    assert!(position(&deps, "bob").unbonding.is_empty());
+    assert!(staking_pool(&deps).pool.total_unbonding.is_zero());
+}
+
+#[test]
+fn claim_staking_rewards_pays_out_once() {
+    let mut deps = setup();
+    stake(&mut deps, "bob", 10);
+    buy(&mut deps, "carl", 1, None).unwrap();
+    
+    let res = run(&mut deps, "bob", &[], ExecuteMsg::ClaimStakingRewards { mint: MINT.to_string() }).unwrap();
+    assert_eq!(bank_send(&res), ("bob".to_string(), coins(5_000_000, "inj")));
+    assert!(position(&deps, "bob").claimable_rewards.is_zero());
+    
+    let err = run(&mut deps, "bob", &[], ExecuteMsg::ClaimStakingRewards { mint: MINT.to_string() }).unwrap_err();
+    assert!(matches!(err, ContractError::NothingToClaim {}));
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use cosmwasm_std::{coins, Response, Uint128};
+use hashmelody::{
+    error::ContractError,
+    msg::{ExecuteMsg, QueryMsg, TokenStatusResponse},
+    state::TokenStatus
+};
+use common::{attr, buy, query_as, register_song, run, setup, TestDeps, MINT, PRICE};
+
+const ALL: [TokenStatus; 7] = [
+    TokenStatus::Draft,
+    TokenStatus::Presale,
+    TokenStatus::Live,
+    TokenStatus::Graduating,
+    TokenStatus::Graduated,
+    TokenStatus::Delisted,
+    TokenStatus::OnHold,
+];
+
+fn status(deps: &TestDeps, mint: &str) -> TokenStatus {
+    query_as::<TokenStatusResponse>(deps, QueryMsg::GetTokenStatus { mint: mint.to_string() }).status
+}
+
+// The from and to statuses of the transition a response records
+fn transition(response: &Response) -> (String, String) {
+    let event = response.events.iter().find(|event| event.ty == "token_status").unwrap();
+    let value = |key: &str| event.attributes.iter().find(|attr| attr.key == key).unwrap().value.clone();
+    (value("from"), value("to"))
+}
+
+fn graduate(deps: &mut TestDeps) -> Result<Response, ContractError> {
+    run(deps, "admin", &[], ExecuteMsg::CompleteGraduation { mint: MINT.to_string(), dex_pool: "pool".to_string() })
+}
+
+#[test]
+fn transition_table() {
+    use TokenStatus::*;
+    
+    let allowed = [
+        (Draft, Presale),
+        (Draft, Live),
+        (Presale, Live),
+        (Live, Presale),
+        (Presale, Graduating),
+        (Live, Graduating),
+        (Graduating, Graduated),
+        (Draft, OnHold),
+        (Presale, OnHold),
+        (Live, OnHold),
+        (Graduating, OnHold),
+        (Graduated, OnHold),
+        (Draft, Delisted),
+        (Presale, Delisted),
+        (Live, Delisted),
+        (Graduating, Delisted),
+        (Graduated, Delisted),
+        (OnHold, Delisted),
+    ];
+    for from in ALL {
+        for to in ALL {
+            assert_eq!(from.can_transition_to(to), allowed.contains(&(from, to)), "{} -> {}", from, to);
+        }
+    }
+    
+    // Delisting is final and graduation cannot be undone; a hold is only lifted by release_hold
+    assert!(ALL.iter().all(|next| !Delisted.can_transition_to(*next)));
+    assert!(!Graduated.can_transition_to(Live) && !Graduating.can_transition_to(Live));
+}
+
+#[test]
+fn tokens_trade_only_once_set_up() {
+    let mut deps = setup();
+    let mint = register_song(&mut deps, "alice", "demo", 2, "Demo");
+    assert_eq!(status(&deps, &mint), TokenStatus::Draft);
+    
+    run(&mut deps, "alice", &[], ExecuteMsg::InitializeTokenOracle { mint: mint.clone() }).unwrap();
+    assert_eq!(status(&deps, &mint), TokenStatus::Draft);
+    let msg = ExecuteMsg::PurchaseToken { mint: mint.clone(), amount_tokens: Uint128::one(), referrer: None, proof: None };
+    let err = run(&mut deps, "bob", &coins(10 * PRICE, "inj"), msg).unwrap_err();
+    assert!(matches!(err, ContractError::TokenNotTradable { status: TokenStatus::Draft, .. }));
+    
+    let res = run(&mut deps, "alice", &[], ExecuteMsg::SetupVaultAccount { mint: mint.clone() }).unwrap();
+    assert_eq!(status(&deps, &mint), TokenStatus::Live);
+    assert_eq!(transition(&res), ("draft".to_string(), "live".to_string()));
+}
+
+#[test]
+fn filling_the_vault_starts_graduation() {
+    let mut deps = setup();
+    
+    // Graduation can only complete for a graduating token
+    let err = graduate(&mut deps).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidTokenStatus { status: TokenStatus::Live }));
+    
+    let res = buy(&mut deps, "bob", 12, None).unwrap();
+    assert_eq!(attr(&res, "status"), "graduating");
+    assert_eq!(transition(&res), ("live".to_string(), "graduating".to_string()));
+    
+    // Trading halts while liquidity moves to the DEX
+    let err = buy(&mut deps, "bob", 1, None).unwrap_err();
+    assert!(matches!(err, ContractError::TokenNotTradable { status: TokenStatus::Graduating, .. }));
+    
+    let err = run(&mut deps, "bob", &[], ExecuteMsg::CompleteGraduation { mint: MINT.to_string(), dex_pool: "pool".to_string() }).unwrap_err();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+    graduate(&mut deps).unwrap();
+    assert_eq!(status(&deps, MINT), TokenStatus::Graduated);
+    let err = graduate(&mut deps).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidTokenStatus { status: TokenStatus::Graduated }));
+}
@@ -0,0 +1,119 @@
+mod common;
+
+use hashmelody::{
+    error::ContractError,
+    msg::{ExecuteMsg, QueryMsg, TokenMetadataResponse},
+    state::SongDetails
+};
+use common::{query_as, run, setup, TestDeps};
+
+const MINT: &str = "factory/alice/other";
+
+const CID_V0: &str = "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const CID_V1: &str = "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi/song.mp3";
+const ARWEAVE: &str = "ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U";
+
+fn register(deps: &mut TestDeps, name: &str, music_uri: &str, details: SongDetails) -> Result<(), ContractError> {
+    let msg = ExecuteMsg::InitializeTokenMetadata {
+        id: 2,
+        name: name.to_string(),
+        music_uri: music_uri.to_string(),
+        content_hash: None,
+        mint: MINT.to_string(),
+        creator: None,
+        royalty_bps: 500,
+        vesting: None,
+        mint_master_nft: None,
+        details: Some(details),
+    };
+    run(deps, "alice", &[], msg).map(|_| ())
+}
+
+fn register_uri(music_uri: &str) -> Result<(), ContractError> {
+    register(&mut setup(), "Other", music_uri, SongDetails::default())
+}
+
+fn register_details(details: SongDetails) -> Result<(), ContractError> {
+    register(&mut setup(), "Other", CID_V0, details)
+}
+
+#[test]
+fn content_addressed_and_https_uris_are_accepted() {
+    for uri in [CID_V0, CID_V1, ARWEAVE, "https://cdn.example.com/song.mp3"] {
+        register_uri(uri).unwrap();
+    }
+}
+
+#[test]
+fn malformed_uris_are_rejected() {
+    let invalid = [
+        "http://example.com/song.mp3",
+        "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG",
+        "https:///song.mp3",
+        "https://example.com/my song.mp3",
+        // A CIDv0 with a character outside base58, a truncated one, and a truncated CIDv1
+        "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd0",
+        "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd",
+        "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf",
+        "ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt",
+    ];
+    for uri in invalid {
+        let err = register_uri(uri).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidUri { field } if field == "music_uri"), "{}", uri);
+    }
+    
+    let long = format!("https://example.com/{}", "a".repeat(237));
+    assert!(matches!(register_uri(&long).unwrap_err(), ContractError::FieldTooLong { max: 256, .. }));
+    let err = register_details(SongDetails { cover_uri: Some("ftp://example.com/cover.png".to_string()), ..SongDetails::default() }).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidUri { field } if field == "cover_uri"));
+}
+
+#[test]
+fn lengths_are_counted_in_characters() {
+    // 32 two-byte characters fit the name limit; 33 do not
+    let mut deps = setup();
+    register(&mut deps, &"é".repeat(32), CID_V0, SongDetails::default()).unwrap();
+    let metadata: TokenMetadataResponse = query_as(&deps, QueryMsg::GetTokenMetadata { mint: MINT.to_string() });
+    assert_eq!(metadata.name.chars().count(), 32);
+    
+    let err = register(&mut setup(), &"é".repeat(33), CID_V0, SongDetails::default()).unwrap_err();
+    assert!(matches!(err, ContractError::FieldTooLong { field, max: 32 } if field == "name"));
+    let err = register_details(SongDetails { artist: Some("a".repeat(65)), ..SongDetails::default() }).unwrap_err();
+    assert!(matches!(err, ContractError::FieldTooLong { field, max: 64 } if field == "artist"));
+}
+
+#[test]
+fn empty_text_is_rejected() {
+    let err = register(&mut setup(), "  ", CID_V0, SongDetails::default()).unwrap_err();
+    assert!(matches!(err, ContractError::EmptyField { field } if field == "name"));
+    let err = register_details(SongDetails { description: Some(String::new()), ..SongDetails::default() }).unwrap_err();
+    assert!(matches!(err, ContractError::EmptyField { field } if field == "description"));
+}
+
+#[test]
+fn isrc_codes_follow_the_standard_layout() {
+    let isrc = |code: &str| SongDetails { isrc: Some(code.to_string()), ..SongDetails::default() };
+    register_details(isrc("USRC17607839")).unwrap();
+    register_details(isrc("GBAYE0601498")).unwrap();
+    
+    // Hyphenated, lowercase, too short, and letters where the year belongs
+    for code in ["US-RC1-76-07839", "usrc17607839", "USRC1760783", "USRC1AB07839"] {
+        let err = register_details(isrc(code)).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidMetadata { field } if field == "isrc"), "{}", code);
+    }
+}
+
+#[test]
+fn other_details_are_checked() {
+    let err = register_details(SongDetails { symbol: Some("SO-NG".to_string()), ..SongDetails::default() }).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidMetadata { field } if field == "symbol"));
+    let err = register_details(SongDetails { duration_seconds: Some(0), ..SongDetails::default() }).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidMetadata { field } if field == "duration_seconds"));
+    
+    let links = vec!["https://example.com".to_string(); 6];
+    let err = register_details(SongDetails { links: Some(links), ..SongDetails::default() }).unwrap_err();
+    assert!(matches!(err, ContractError::TooManyLinks { max: 5 }));
+    let links = vec!["https://example.com".to_string(), "mailto:alice@example.com".to_string()];
+    let err = register_details(SongDetails { links: Some(links), ..SongDetails::default() }).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidUri { field } if field == "links"));
+}
@@ -0,0 +1,114 @@
+mod common;
+
+use cosmwasm_std::{coins, from_json, Response, Uint128};
+use hashmelody::{
+    contract::query,
+    error::ContractError,
+    msg::{ExecuteMsg, QueryMsg, VestingParams, VestingResponse}
+};
+use common::{at, bank_send, run_at, setup, TestDeps};
+
+const PREMINE: &str = "factory/alice/premine";
+
+// Alice's second song, pre-mining `amount` tokens for her on a vesting schedule
+fn vesting_song(amount: u128, cliff_seconds: u64, duration_seconds: u64) -> ExecuteMsg {
+    ExecuteMsg::InitializeTokenMetadata {
+        id: 2,
+        name: "Premine".to_string(),
+        music_uri: "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_string(),
+        content_hash: None,
+        mint: PREMINE.to_string(),
+        creator: None,
+        royalty_bps: 500,
+        vesting: Some(VestingParams {
+            amount: Uint128::new(amount),
+            cliff_seconds,
+            duration_seconds,
+        }),
+        mint_master_nft: None,
+        details: None,
+    }
+}
+
+fn vesting_at(deps: &TestDeps, seconds: u64) -> VestingResponse {
+    let msg = QueryMsg::GetVesting { mint: PREMINE.to_string(), address: "alice".to_string() };
+    from_json(query(deps.as_ref(), at(seconds), msg).unwrap()).unwrap()
+}
+
+fn claim_at(deps: &mut TestDeps, seconds: u64) -> Result<Response, ContractError> {
+    run_at(deps, at(seconds), "alice", &[], ExecuteMsg::ClaimVested { mint: PREMINE.to_string() })
+}
+
+// 1_000 tokens vesting over 1_000 seconds after a 100 second cliff
+fn vesting_deps() -> TestDeps {
+    let mut deps = setup();
+    run_at(&mut deps, at(0), "alice", &coins(1_000, PREMINE), vesting_song(1_000, 100, 1_000)).unwrap();
+    deps
+}
+
+#[test]
+fn vesting_needs_a_matching_deposit_and_valid_schedule() {
+    // Failed messages revert on chain, so each case starts from a fresh contract
+    let err = run_at(&mut setup(), at(0), "alice", &coins(999, PREMINE), vesting_song(1_000, 100, 1_000)).unwrap_err();
+    assert!(matches!(err, ContractError::InvalidDeposit { expected, .. } if expected == Uint128::new(1_000)));
+    
+    // A cliff past the end of the schedule, no duration, or nothing to vest
+    let deposit = coins(1_000, PREMINE);
+    for msg in [vesting_song(1_000, 1_001, 1_000), vesting_song(1_000, 0, 0), vesting_song(0, 100, 1_000)] {
+        let err = run_at(&mut setup(), at(0), "alice", &deposit, msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidVesting {}));
+    }
+}
+
+#[test]
+fn nothing_vests_before_the_cliff() {
+    let mut deps = vesting_deps();
+    
+    let vesting = vesting_at(&deps, 99);
+    assert_eq!(vesting.vested, Uint128::zero());
+    assert_eq!((vesting.cliff_time - vesting.start_time, vesting.end_time - vesting.start_time), (100, 1_000));
+    
+    let err = claim_at(&mut deps, 99).unwrap_err();
+    assert!(matches!(err, ContractError::NothingToClaim {}));
+}
+
+#[test]
+fn vesting_releases_linearly_after_the_cliff() {
+    let mut deps = vesting_deps();
+    
+    // At the cliff the time since the start has vested at once
+    assert_eq!(vesting_at(&deps, 100).vested, Uint128::new(100));
+    let res = claim_at(&mut deps, 100).unwrap();
+    assert_eq!(bank_send(&res), ("alice".to_string(), coins(100, PREMINE)));
+    
+    // Claims only pay what vested since the last one
+    let res = claim_at(&mut deps, 400).unwrap();
+    assert_eq!(bank_send(&res), ("alice".to_string(), coins(300, PREMINE)));
+    let err = claim_at(&mut deps, 400).unwrap_err();
+    assert!(matches!(err, ContractError::NothingToClaim {}));
+    
+    let vesting = vesting_at(&deps, 700);
+    assert_eq!((vesting.vested, vesting.claimed, vesting.claimable), (Uint128::new(700), Uint128::new(400), Uint128::new(300)));
+}
+
+#[test]
+fn everything_vests_at_the_end() {
+    let mut deps = vesting_deps();
+    
+    let res = claim_at(&mut deps, 5_000).unwrap();
+    assert_eq!(bank_send(&res), ("alice".to_string(), coins(1_000, PREMINE)));
+    assert_eq!(vesting_at(&deps, 5_000).claimable, Uint128::zero());
+    let err = claim_at(&mut deps, 6_000).unwrap_err();
+    assert!(matches!(err, ContractError::NothingToClaim {}));
+}
+
+#[test]
+fn only_the_beneficiary_has_a_schedule() {
+    let mut deps = vesting_deps();
+    
+    let err = run_at(&mut deps, at(500), "bob", &[], ExecuteMsg::ClaimVested { mint: PREMINE.to_string() }).unwrap_err();
+    assert!(matches!(err, ContractError::NothingToClaim {}));
+    let msg = QueryMsg::GetVesting { mint: PREMINE.to_string(), address: "bob".to_string() };
+    let err = query(deps.as_ref(), at(500), msg).unwrap_err();
+    assert!(matches!(err, ContractError::VestingNotFound { .. }));
+}